@@ -0,0 +1,247 @@
+use super::coxeter_group::CoxeterGroup;
+use super::root_system::{root_key, RootSystem};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+// The automaton of Brink and Howlett, whose states are sets of small roots, given by their indices
+// in `RootSystem::small_roots`. After reading a reduced word `w` the state holds the small roots
+// `b` with `w(b) < 0`, so appending `s` keeps the word reduced iff `a_s` is not in the state, and
+// the next state is `{a_s} + s(state)` restricted to small roots. For shortlex normal forms the
+// roots `s(a_t)` with `t < s` are added as well, which rules out the letters that could be moved in
+// front of `s`. Every state accepts; words are rejected by running into a missing transition.
+pub struct Automaton {
+    states: Vec<Vec<usize>>,
+    transitions: Vec<Vec<Option<usize>>>,
+}
+
+impl Automaton {
+    pub fn reduced_words(group: &CoxeterGroup) -> Self {
+        Self::new(group, false)
+    }
+
+    pub fn shortlex_normal_forms(group: &CoxeterGroup) -> Self {
+        Self::new(group, true)
+    }
+
+    fn new(group: &CoxeterGroup, shortlex: bool) -> Self {
+        let rank = group.rank();
+        let small_roots = RootSystem::small_roots(group);
+        let indices: HashMap<_, _> = small_roots
+            .roots()
+            .iter()
+            .enumerate()
+            .map(|(index, root)| (root_key(group, root.coefficients()), index))
+            .collect();
+        // `reflections[i][b]` is the index of `s_i(b)` if it is a small root. The simple roots come
+        // first among the small roots.
+        let reflections = (0..rank)
+            .map(|i| {
+                small_roots
+                    .roots()
+                    .iter()
+                    .map(|root| {
+                        indices
+                            .get(&root_key(
+                                group,
+                                &small_roots.reflect(i, root.coefficients()),
+                            ))
+                            .copied()
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+        let mut states = vec![Vec::new()];
+        let mut state_indices = HashMap::from([(Vec::new(), 0)]);
+        let mut transitions = Vec::new();
+        while transitions.len() < states.len() {
+            let state: Vec<usize> = states[transitions.len()].clone();
+            let transition = (0..rank)
+                .map(|i| {
+                    (!state.contains(&i)).then(|| {
+                        let next = std::iter::once(i)
+                            .chain(state.iter().filter_map(|&b| reflections[i][b]))
+                            .chain(
+                                (0..i)
+                                    .filter(|_| shortlex)
+                                    .filter_map(|j| reflections[i][j]),
+                            )
+                            .sorted()
+                            .dedup()
+                            .collect_vec();
+                        *state_indices.entry(next).or_insert_with_key(|next| {
+                            states.push(next.clone());
+                            states.len() - 1
+                        })
+                    })
+                })
+                .collect_vec();
+            transitions.push(transition);
+        }
+        Self {
+            states,
+            transitions,
+        }
+    }
+
+    pub fn states(&self) -> &[Vec<usize>] {
+        &self.states
+    }
+
+    pub fn initial_state(&self) -> usize {
+        0
+    }
+
+    pub fn accept_states(&self) -> Vec<usize> {
+        (0..self.states.len()).collect()
+    }
+
+    // `transitions()[state][i]` is the state reached by reading `i`, if any.
+    pub fn transitions(&self) -> &[Vec<Option<usize>>] {
+        &self.transitions
+    }
+
+    pub fn accepts(&self, word: &[usize]) -> bool {
+        word.iter()
+            .try_fold(self.initial_state(), |state, &i| self.transitions[state][i])
+            .is_some()
+    }
+
+    // The numbers of accepted words of lengths `0..=max_length`.
+    pub fn count_by_length(&self, max_length: usize) -> Vec<u64> {
+        let mut counts = vec![0; self.states.len()];
+        counts[self.initial_state()] = 1;
+        (0..=max_length)
+            .map(|_| {
+                let total = counts.iter().sum();
+                let mut next_counts = vec![0; self.states.len()];
+                self.transitions
+                    .iter()
+                    .zip(&counts)
+                    .for_each(|(transition, &count)| {
+                        transition.iter().flatten().for_each(|&next| {
+                            next_counts[next] += count;
+                        });
+                    });
+                counts = next_counts;
+                total
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Automaton;
+    use crate::coxeter_diagram::{CoxeterDiagram, CoxeterDiagramType, CoxeterLabel};
+    use crate::coxeter_group::CoxeterGroup;
+    use crate::root_system::RootSystem;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_small_roots() {
+        // All roots of a finite group are small.
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::H(3)));
+        assert_eq!(RootSystem::small_roots(&group).roots().len(), 15);
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::AffineA(1)));
+        assert_eq!(RootSystem::small_roots(&group).roots().len(), 2);
+        // In affine groups the small roots are the positive roots `b` with `d - b` not positive
+        // for the null root `d`, one for each positive root of the finite root system and its
+        // negative.
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::AffineA(2)));
+        assert_eq!(RootSystem::small_roots(&group).roots().len(), 6);
+    }
+
+    #[test]
+    fn test_automaton() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::A(2)));
+        assert_eq!(
+            Automaton::reduced_words(&group).count_by_length(4),
+            vec![1, 2, 2, 2, 0],
+        );
+        assert_eq!(
+            Automaton::shortlex_normal_forms(&group).count_by_length(4),
+            vec![1, 2, 2, 1, 0],
+        );
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::H(4)));
+        assert_eq!(
+            Automaton::shortlex_normal_forms(&group)
+                .count_by_length(60)
+                .iter()
+                .sum::<u64>(),
+            14400,
+        );
+        [
+            CoxeterDiagram::from(CoxeterDiagramType::AffineA(2)),
+            CoxeterDiagram::from(CoxeterDiagramType::AffineB(3)),
+            CoxeterDiagram::new(
+                3,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((1, 2), CoxeterLabel::Finite(7)),
+                ],
+            )
+            .unwrap(),
+            CoxeterDiagram::new(
+                3,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((1, 2), CoxeterLabel::Infinite),
+                ],
+            )
+            .unwrap(),
+            CoxeterDiagram::new(
+                4,
+                [
+                    ((0, 1), CoxeterLabel::Finite(5)),
+                    ((1, 2), CoxeterLabel::Finite(3)),
+                    ((2, 3), CoxeterLabel::Finite(5)),
+                ],
+            )
+            .unwrap(),
+        ]
+        .into_iter()
+        .for_each(|coxeter_diagram| {
+            let group = CoxeterGroup::new(coxeter_diagram.clone());
+            assert_eq!(
+                Automaton::shortlex_normal_forms(&group)
+                    .count_by_length(20)
+                    .into_iter()
+                    .map(|count| count as i64)
+                    .collect_vec(),
+                coxeter_diagram.growth_series().coefficients(21),
+            );
+        });
+    }
+
+    #[test]
+    fn test_automaton_words() {
+        // The (2, 3, 7) triangle group.
+        let group = CoxeterGroup::new(
+            CoxeterDiagram::new(
+                3,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((1, 2), CoxeterLabel::Finite(7)),
+                ],
+            )
+            .unwrap(),
+        );
+        let reduced_words = Automaton::reduced_words(&group);
+        let shortlex_normal_forms = Automaton::shortlex_normal_forms(&group);
+        (0..=5).for_each(|length| {
+            std::iter::repeat_n(0..3, length)
+                .multi_cartesian_product()
+                .for_each(|word| {
+                    let element = group.element(&word);
+                    assert_eq!(
+                        reduced_words.accepts(&word),
+                        group.length(&element) == word.len(),
+                    );
+                    assert_eq!(
+                        shortlex_normal_forms.accepts(&word),
+                        group.normal_form(&element) == word,
+                    );
+                });
+        });
+    }
+}
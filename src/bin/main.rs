@@ -16,7 +16,17 @@ fn main() {
                 if coxeter_group_type == coxeter_diagram::CoxeterGroupType::Elliptic
                     || coxeter_group_type == coxeter_diagram::CoxeterGroupType::Parabolic
                 {
-                    println!("{:?} -> {:?}", &coxeter_diagram, coxeter_group_type);
+                    println!(
+                        "{} -> {:?}",
+                        coxeter_diagram
+                            .component_types()
+                            .into_iter()
+                            .map(|(_, component_type)| component_type
+                                .map_or_else(|| String::from("?"), |t| t.to_string()))
+                            .collect::<Vec<_>>()
+                            .join(" \u{d7} "),
+                        coxeter_group_type
+                    );
                 }
                 if coxeter_group_type == coxeter_diagram::CoxeterGroupType::Elliptic {
                     has_elliptic = true;
@@ -0,0 +1,411 @@
+use super::coxeter_group::CoxeterGroup;
+use super::numeric::{numeric, symmetric_eigenvectors};
+use super::square_matrix::characteristic_polynomial;
+use feanor_math::{
+    ring::RingStore,
+    rings::poly::{dense_poly::DensePolyRing, PolyRingStore},
+};
+use itertools::Itertools;
+use std::collections::{HashMap, VecDeque};
+
+// Why a character table could not be computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CharacterTableError {
+    Infinite,
+    // The fixed weights gave eigenvalues too close to separate the characters numerically.
+    CloseEigenvalues,
+    // The characters found fail the orthogonality relations or have nonintegral degrees.
+    Inaccurate,
+}
+
+// The irreducible characters of a finite group, computed numerically with Burnside's method. Every
+// element of a finite Coxeter group is conjugate to its inverse, so multiplication by a real
+// combination of class sums `C_k` is self-adjoint in the basis `C_k / sqrt|C_k|` of the center of
+// the group algebra. Its eigenvectors are the central idempotents, with coordinates proportional to
+// `chi(g_k) sqrt|C_k|`.
+pub struct CharacterTable {
+    order: usize,
+    degrees: Vec<u64>,
+    class_representatives: Vec<Vec<usize>>,
+    class_sizes: Vec<usize>,
+    // `characters[chi][k]` is the value on the class `k`.
+    characters: Vec<Vec<f64>>,
+    // The coefficients of `det(1 - t w)` on the reflection representation for the class
+    // representatives `w`, starting from the constant term.
+    determinants: Vec<Vec<f64>>,
+}
+
+impl CharacterTable {
+    // The table is checked against the orthogonality relations and the integrality of the
+    // degrees before it is returned.
+    pub fn new(group: &CoxeterGroup) -> Result<Self, CharacterTableError> {
+        let degrees = group
+            .coxeter_diagram()
+            .coxeter_group_info()
+            .ok_or(CharacterTableError::Infinite)?
+            .degrees;
+        let rank = group.rank();
+        let longest_element = group
+            .longest_element()
+            .ok_or(CharacterTableError::Infinite)?;
+        let elements = group.elements_up_to_length(group.length(&longest_element));
+        let order = elements.len();
+        let indices: HashMap<_, _> = elements
+            .iter()
+            .enumerate()
+            .map(|(index, element)| (group.key(element), index))
+            .collect();
+        let index = |element| indices[&group.key(&element)];
+        // `right[w][s]` and `left[w][s]` are the indices of `ws` and `sw`.
+        let right = elements
+            .iter()
+            .map(|element| {
+                (0..rank)
+                    .map(|s| index(group.mul_generator(group.clone_el(element), s)))
+                    .collect_vec()
+            })
+            .collect_vec();
+        let left = elements
+            .iter()
+            .map(|element| {
+                (0..rank)
+                    .map(|s| index(group.generator_mul(s, group.clone_el(element))))
+                    .collect_vec()
+            })
+            .collect_vec();
+        let mut classes = vec![usize::MAX; order];
+        let mut class_representatives = Vec::new();
+        let mut class_sizes = Vec::new();
+        (0..order).for_each(|w| {
+            if classes[w] != usize::MAX {
+                return;
+            }
+            let class = class_sizes.len();
+            classes[w] = class;
+            let mut size = 1;
+            let mut queue = VecDeque::from([w]);
+            while let Some(v) = queue.pop_front() {
+                (0..rank).for_each(|s| {
+                    let conjugate = left[right[v][s]][s];
+                    if classes[conjugate] == usize::MAX {
+                        classes[conjugate] = class;
+                        size += 1;
+                        queue.push_back(conjugate);
+                    }
+                });
+            }
+            class_representatives.push(w);
+            class_sizes.push(size);
+        });
+        let class_count = class_sizes.len();
+        // A spanning tree of the Cayley graph, with `parents[x] = (p, s)` and `x = ps`, so that
+        // `y = x^-1 z` is found from `y(p)` as `s y(p)`.
+        let mut parents = vec![None; order];
+        let mut visited = vec![false; order];
+        visited[0] = true;
+        let mut tree_order = vec![0];
+        let mut position = 0;
+        while position < tree_order.len() {
+            let p = tree_order[position];
+            (0..rank).for_each(|s| {
+                let x = right[p][s];
+                if !visited[x] {
+                    visited[x] = true;
+                    parents[x] = Some((p, s));
+                    tree_order.push(x);
+                }
+            });
+            position += 1;
+        }
+        // Fixed weights assumed generic: the eigenvalue `sum_i r_i |C_i| chi(g_i) / chi(1)` for the
+        // character `chi` separates the characters unless the weights lie on one of finitely many
+        // hyperplanes, which is checked below.
+        let weights = (0..class_count)
+            .map(|i| (i as f64 + 1.0).sin())
+            .collect_vec();
+        // `C C_j = sum_k m_kj C_k` for `C = sum_i r_i C_i`, where `m_kj` counts the `x` with
+        // `x^-1 z in C_j` for a fixed `z` in `C_k`, weighted by the class of `x`.
+        let mut matrix = vec![vec![0.0; class_count]; class_count];
+        (0..class_count).for_each(|k| {
+            let mut quotients = vec![0; order];
+            tree_order.iter().for_each(|&x| {
+                quotients[x] = match parents[x] {
+                    None => class_representatives[k],
+                    Some((p, s)) => left[quotients[p]][s],
+                };
+                matrix[k][classes[quotients[x]]] += weights[classes[x]];
+            });
+        });
+        let symmetric = (0..class_count)
+            .map(|k| {
+                (0..class_count)
+                    .map(|j| matrix[k][j] * (class_sizes[k] as f64 / class_sizes[j] as f64).sqrt())
+                    .collect_vec()
+            })
+            .collect_vec();
+        let eigenvectors = symmetric_eigenvectors(symmetric.clone());
+        // Close eigenvalues, relative to the largest one, leave the eigenvectors mixed instead of
+        // giving the characters.
+        let eigenvalues = eigenvectors
+            .iter()
+            .map(|eigenvector| {
+                (0..class_count)
+                    .map(|k| {
+                        eigenvector[k]
+                            * (0..class_count)
+                                .map(|j| symmetric[k][j] * eigenvector[j])
+                                .sum::<f64>()
+                    })
+                    .sum::<f64>()
+            })
+            .sorted_by(f64::total_cmp)
+            .collect_vec();
+        let scale = eigenvalues
+            .iter()
+            .map(|eigenvalue| eigenvalue.abs())
+            .fold(1.0, f64::max);
+        if eigenvalues
+            .iter()
+            .tuple_windows()
+            .any(|(lhs, rhs)| rhs - lhs < 1e-9 * scale)
+        {
+            return Err(CharacterTableError::CloseEigenvalues);
+        }
+        let characters = eigenvectors
+            .into_iter()
+            .map(|eigenvector| {
+                let sign = eigenvector[0].signum();
+                (0..class_count)
+                    .map(|k| sign * eigenvector[k] * (order as f64 / class_sizes[k] as f64).sqrt())
+                    .collect_vec()
+            })
+            .sorted_by_key(|character| {
+                (
+                    character[0].round() as i64,
+                    character
+                        .iter()
+                        .map(|value| -(value * 1e6).round() as i64)
+                        .collect_vec(),
+                )
+            })
+            .collect_vec();
+        let integral = characters
+            .iter()
+            .all(|character| (character[0] - character[0].round()).abs() < 1e-6);
+        let orthogonal = characters.iter().enumerate().all(|(i, lhs)| {
+            characters.iter().enumerate().all(|(j, rhs)| {
+                let product = (0..class_count)
+                    .map(|k| lhs[k] * rhs[k] * class_sizes[k] as f64)
+                    .sum::<f64>()
+                    / order as f64;
+                (product - (i == j) as u8 as f64).abs() < 1e-6
+            })
+        });
+        if !integral || !orthogonal {
+            return Err(CharacterTableError::Inaccurate);
+        }
+        let cr = group.cyclotomic_ring();
+        let mr = group.coxeter_diagram().matrix_ring();
+        let pr = DensePolyRing::new(cr.clone(), "t");
+        let determinants = class_representatives
+            .iter()
+            .map(|&w| {
+                let polynomial =
+                    characteristic_polynomial(&mr, &pr, mr.clone_el(group.matrix(&elements[w])));
+                (0..=rank)
+                    .rev()
+                    .map(|degree| numeric(cr, pr.coefficient_at(&polynomial, degree)))
+                    .collect_vec()
+            })
+            .collect();
+        Ok(Self {
+            order,
+            degrees,
+            class_representatives: class_representatives
+                .into_iter()
+                .map(|w| group.reduced_word(&elements[w]))
+                .collect(),
+            class_sizes,
+            characters,
+            determinants,
+        })
+    }
+
+    // Reduced words of the class representatives, the first class being that of the identity.
+    pub fn class_representatives(&self) -> &[Vec<usize>] {
+        &self.class_representatives
+    }
+
+    pub fn class_sizes(&self) -> &[usize] {
+        &self.class_sizes
+    }
+
+    // The irreducible characters sorted by dimension, the trivial character first.
+    pub fn characters(&self) -> &[Vec<f64>] {
+        &self.characters
+    }
+
+    // The character of the reflection representation, which is irreducible for irreducible groups.
+    pub fn reflection_character(&self) -> Option<usize> {
+        self.characters.iter().position(|character| {
+            character
+                .iter()
+                .zip(&self.determinants)
+                .all(|(value, determinant)| (value + determinant[1]).abs() < 1e-6)
+        })
+    }
+
+    // The multiplicities of the character in the homogeneous components of degrees
+    // `0..=max_degree` of the polynomial functions, by Molien's formula
+    // `1 / |W| sum_w chi(w) / det(1 - t w)`.
+    pub fn graded_multiplicity(&self, character: usize, max_degree: usize) -> Vec<i64> {
+        let mut series = vec![0.0; max_degree + 1];
+        self.determinants
+            .iter()
+            .zip(&self.class_sizes)
+            .zip(&self.characters[character])
+            .for_each(|((determinant, &size), &value)| {
+                let mut inverse = vec![0.0; max_degree + 1];
+                inverse[0] = 1.0;
+                (1..=max_degree).for_each(|m| {
+                    inverse[m] = -(1..=m.min(determinant.len() - 1))
+                        .map(|j| determinant[j] * inverse[m - j])
+                        .sum::<f64>();
+                });
+                series
+                    .iter_mut()
+                    .zip(inverse)
+                    .for_each(|(coefficient, c)| *coefficient += size as f64 * value * c);
+            });
+        series
+            .into_iter()
+            .map(|coefficient| {
+                let multiplicity = coefficient / self.order as f64;
+                assert!(
+                    (multiplicity - multiplicity.round()).abs() < 1e-6,
+                    "nonintegral multiplicity {multiplicity}",
+                );
+                multiplicity.round() as i64
+            })
+            .collect()
+    }
+
+    pub fn molien_series(&self, max_degree: usize) -> Vec<i64> {
+        self.graded_multiplicity(0, max_degree)
+    }
+
+    // The graded multiplicity of the character in the coinvariant algebra, obtained from the
+    // polynomial functions by multiplying with `prod_i (1 - t^d_i)`.
+    pub fn fake_degree(&self, character: usize) -> Vec<i64> {
+        let max_degree = self.degrees.iter().map(|&degree| degree as usize - 1).sum();
+        let mut fake_degree = self.graded_multiplicity(character, max_degree);
+        self.degrees.iter().for_each(|&degree| {
+            (degree as usize..=max_degree).rev().for_each(|m| {
+                fake_degree[m] -= fake_degree[m - degree as usize];
+            });
+        });
+        while fake_degree.last() == Some(&0) {
+            fake_degree.pop();
+        }
+        fake_degree
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CharacterTable, CharacterTableError};
+    use crate::coxeter_diagram::{CoxeterDiagram, CoxeterDiagramType};
+    use crate::coxeter_group::CoxeterGroup;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_character_table() {
+        [
+            (CoxeterDiagramType::A(3), 5),
+            (CoxeterDiagramType::B(3), 10),
+            (CoxeterDiagramType::D(4), 13),
+            (CoxeterDiagramType::F(4), 25),
+            (CoxeterDiagramType::H(3), 10),
+            (CoxeterDiagramType::I2(5), 4),
+        ]
+        .into_iter()
+        .for_each(|(coxeter_diagram_type, class_count)| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            let info = coxeter_diagram.coxeter_group_info().unwrap();
+            let group = CoxeterGroup::new(coxeter_diagram);
+            let character_table = CharacterTable::new(&group).unwrap();
+            let characters = character_table.characters();
+            assert_eq!(characters.len(), class_count);
+            // Orthogonality relations.
+            characters.iter().enumerate().for_each(|(i, lhs)| {
+                characters.iter().enumerate().for_each(|(j, rhs)| {
+                    let product = lhs
+                        .iter()
+                        .zip(rhs)
+                        .zip(character_table.class_sizes())
+                        .map(|((l, r), &size)| l * r * size as f64)
+                        .sum::<f64>()
+                        / info.order as f64;
+                    assert!((product - (i == j) as u8 as f64).abs() < 1e-6);
+                });
+            });
+            // The exponents are the fake degree of the reflection representation.
+            let reflection_character = character_table.reflection_character().unwrap();
+            let fake_degree = character_table.fake_degree(reflection_character);
+            assert_eq!(
+                info.exponents
+                    .iter()
+                    .map(|&exponent| exponent as usize)
+                    .collect_vec(),
+                fake_degree
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(degree, &multiplicity)| {
+                        std::iter::repeat_n(degree, multiplicity as usize)
+                    })
+                    .collect_vec(),
+            );
+            // The coinvariant algebra is a graded version of the regular representation.
+            let mut poincare_polynomial = vec![0; info.exponents.iter().sum::<u64>() as usize + 1];
+            characters.iter().enumerate().for_each(|(i, character)| {
+                character_table.fake_degree(i).iter().enumerate().for_each(
+                    |(degree, &multiplicity)| {
+                        assert!(multiplicity >= 0);
+                        poincare_polynomial[degree] += character[0].round() as i64 * multiplicity;
+                    },
+                );
+            });
+            assert_eq!(
+                poincare_polynomial,
+                group.coxeter_diagram().growth_series().numerator,
+            );
+        });
+    }
+
+    #[test]
+    fn test_molien_series() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::A(3)));
+        let character_table = CharacterTable::new(&group).unwrap();
+        // `1 / ((1 - t^2)(1 - t^3)(1 - t^4))`.
+        assert_eq!(
+            character_table.molien_series(8),
+            vec![1, 0, 1, 1, 2, 1, 3, 2, 4],
+        );
+        // The sign character first appears in degree 6 as the product of the roots.
+        let sign_character = 1;
+        assert!(character_table.characters()[sign_character][1] < 0.0);
+        assert_eq!(
+            character_table.fake_degree(sign_character),
+            vec![0, 0, 0, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            character_table.graded_multiplicity(sign_character, 8),
+            vec![0, 0, 0, 0, 0, 0, 1, 0, 1],
+        );
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::AffineA(2)));
+        assert_eq!(
+            CharacterTable::new(&group).err(),
+            Some(CharacterTableError::Infinite)
+        );
+    }
+}
@@ -1,20 +1,51 @@
 use super::cyclotomic::{
-    cyclotomic_numeric_embed_into, cyclotomic_root_of_unity, CyclotomicRingBase,
+    cyclotomic_coefficients, cyclotomic_numeric_embed_into, cyclotomic_root_of_unity,
+    cyclotomic_sign, gcd, lcm, CyclotomicRingBase,
 };
 use super::dynkin_diagram::DynkinDiagramType;
-use super::square_matrix::{determinant, SquareMatrixRingBase};
+use super::numeric::numeric_matrix;
+use super::square_matrix::{characteristic_polynomial, SquareMatrixRingBase};
 use feanor_math::{
+    algorithms::poly_gcd::factor::poly_factor_integer,
+    computation::DontObserve,
+    field::FieldStore,
+    integer::{int_cast, BigIntRing},
     matrix::OwnedMatrix,
-    ring::{RingBase, RingStore, RingValue},
-    rings::poly::PolyRingStore,
+    ordered::OrderedRingStore,
+    primitive_int::StaticRing,
+    ring::{El, RingBase, RingStore, RingValue},
+    rings::{
+        float_complex::{Complex64, Complex64El},
+        poly::{dense_poly::DensePolyRing, PolyRingStore},
+    },
 };
 use itertools::Itertools;
+use petgraph::visit::EdgeRef;
+use std::collections::{BTreeMap, HashMap};
+
+type CyclotomicRing = RingValue<CyclotomicRingBase<StaticRing<i64>>>;
+type Matrix = <SquareMatrixRingBase<CyclotomicRing> as RingBase>::Element;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum CoxeterGroupType {
     Elliptic,
     Parabolic,
+    // Signature (n - 1, 0, 1) with every proper subdiagram elliptic (Lanner diagrams).
+    CompactHyperbolic,
+    // Signature (n - 1, 0, 1) with every proper subdiagram elliptic or parabolic, some of them
+    // parabolic (Koszul diagrams).
     Hyperbolic,
+    // Signature (n - 1, 0, 1) with some proper subdiagram neither elliptic nor parabolic.
+    Lorentzian,
+    // Negative index at least 2, or degenerate with negative index 1.
+    Indefinite,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub positive: usize,
+    pub zero: usize,
+    pub negative: usize,
 }
 
 #[derive(Debug, PartialEq)]
@@ -25,29 +56,111 @@ pub struct CoxeterGroupInfo {
     pub degrees: Vec<u64>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeadingRoot {
+    // Every root lies on the unit circle, as for finite and affine groups.
+    RootOfUnity,
+    Salem,
+    Pisot,
+    Other,
+}
+
+// The characteristic polynomial of the Coxeter element with coefficients starting from the constant
+// term. When some coefficient is irrational, `integer_polynomial` is its norm, the product of its
+// Galois conjugates, and the factorization is that of the norm.
+#[derive(Debug, PartialEq)]
+pub struct CoxeterPolynomial {
+    pub integer_polynomial: Vec<i64>,
+    pub is_integral: bool,
+    // Pairs of an order `k` and the multiplicity of `Phi_k`.
+    pub cyclotomic_factors: Vec<(u64, usize)>,
+    pub non_cyclotomic_factors: Vec<(Vec<i64>, usize)>,
+    pub spectral_radius: f64,
+    pub leading_root: LeadingRoot,
+}
+
+// The rational function `W(t) = numerator(t) / denominator(t)` given by coefficients starting from
+// the constant term, with `denominator(0) = 1`.
+#[derive(Debug, PartialEq)]
+pub struct GrowthSeries {
+    pub numerator: Vec<i64>,
+    pub denominator: Vec<i64>,
+}
+
+impl GrowthSeries {
+    // The numbers of elements of lengths `0..count`.
+    pub fn coefficients(&self, count: usize) -> Vec<i64> {
+        let mut coefficients = Vec::with_capacity(count);
+        (0..count).for_each(|degree| {
+            let coefficient = self.numerator.get(degree).copied().unwrap_or(0)
+                - (1..self.denominator.len().min(degree + 1))
+                    .map(|index| self.denominator[index] * coefficients[degree - index])
+                    .sum::<i64>();
+            coefficients.push(coefficient);
+        });
+        coefficients
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CoxeterLabel {
+    Finite(u64),
+    Infinite,
+}
+
+// Finite labels are at least 2.
+impl TryFrom<u64> for CoxeterLabel {
+    type Error = u64;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        if value >= 2 {
+            Ok(Self::Finite(value))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl std::ops::AddAssign<u64> for CoxeterLabel {
+    fn add_assign(&mut self, rhs: u64) {
+        match self {
+            Self::Finite(label) => *label += rhs,
+            // `infinity + rhs = infinity`.
+            Self::Infinite => {}
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
-pub struct CoxeterDiagram(petgraph::graph::UnGraph<(), u64>);
+pub struct CoxeterDiagram(petgraph::graph::UnGraph<(), CoxeterLabel>);
 
 impl CoxeterDiagram {
-    pub fn new<I>(rank: usize, labels: I) -> Self
+    // Returns `None` for a finite label below 2, a loop, a node out of range or an edge given twice.
+    pub fn new<I>(rank: usize, labels: I) -> Option<Self>
     where
-        I: IntoIterator<Item = ((usize, usize), u64)>,
+        I: IntoIterator<Item = ((usize, usize), CoxeterLabel)>,
     {
         let mut graph = petgraph::graph::UnGraph::new_undirected();
         (0..rank).for_each(|_| {
             graph.add_node(());
         });
-        labels.into_iter().for_each(|((i, j), label)| {
-            graph.add_edge(
+        labels.into_iter().try_for_each(|((i, j), label)| {
+            let (i, j) = (
                 petgraph::graph::NodeIndex::new(i),
                 petgraph::graph::NodeIndex::new(j),
-                label,
             );
-        });
-        Self(graph)
+            (i != j
+                && i.index().max(j.index()) < rank
+                && graph.find_edge(i, j).is_none()
+                && !matches!(label, CoxeterLabel::Finite(0..=1)))
+            .then(|| {
+                graph.add_edge(i, j, label);
+            })
+        })?;
+        Some(Self(graph))
     }
 
-    fn get_edge(&self, i: usize, j: usize) -> Option<&u64> {
+    fn get_edge(&self, i: usize, j: usize) -> Option<&CoxeterLabel> {
         self.0
             .find_edge(
                 petgraph::graph::NodeIndex::new(i),
@@ -56,6 +169,30 @@ impl CoxeterDiagram {
             .map(|edge_index| self.0.edge_weight(edge_index).unwrap())
     }
 
+    pub fn subdiagram(&self, nodes: &[usize]) -> Self {
+        Self::new(
+            nodes.len(),
+            nodes.iter().enumerate().tuple_combinations().filter_map(
+                |((i, &node_i), (j, &node_j))| {
+                    self.get_edge(node_i, node_j).map(|&label| ((i, j), label))
+                },
+            ),
+        )
+        .unwrap()
+    }
+
+    fn extended<I>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, CoxeterLabel)>,
+    {
+        let node = self.0.add_node(());
+        labels.into_iter().for_each(|(i, label)| {
+            self.0
+                .add_edge(petgraph::graph::NodeIndex::new(i), node, label);
+        });
+        self
+    }
+
     pub fn is_subgraph_of(&self, other: &Self) -> bool {
         petgraph::algo::is_isomorphic_subgraph_matching(
             &self.0,
@@ -65,11 +202,198 @@ impl CoxeterDiagram {
         )
     }
 
+    // Whether the diagram comes from a root system with integral Cartan matrix.
+    pub fn is_crystallographic(&self) -> bool {
+        self.0.edge_weights().all(|label| {
+            matches!(
+                label,
+                CoxeterLabel::Finite(2 | 3 | 4 | 6) | CoxeterLabel::Infinite
+            )
+        })
+    }
+
     pub fn rank(&self) -> usize {
         self.0.node_count()
     }
 
-    fn cyclotomic_ring(
+    fn neighbors(&self, i: usize) -> Vec<(usize, CoxeterLabel)> {
+        self.0
+            .edges(petgraph::graph::NodeIndex::new(i))
+            .filter(|edge| *edge.weight() != CoxeterLabel::Finite(2))
+            .map(|edge| {
+                let (source, target) = (edge.source().index(), edge.target().index());
+                (if source == i { target } else { source }, *edge.weight())
+            })
+            .sorted()
+            .collect()
+    }
+
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut union_find = petgraph::unionfind::UnionFind::new(self.rank());
+        (0..self.rank()).for_each(|i| {
+            self.neighbors(i).into_iter().for_each(|(j, _)| {
+                union_find.union(i, j);
+            });
+        });
+        (0..self.rank())
+            .map(|i| (union_find.find(i), i))
+            .into_group_map()
+            .into_values()
+            .sorted()
+            .collect()
+    }
+
+    pub fn component_types(&self) -> Vec<(Vec<usize>, Option<CoxeterDiagramType>)> {
+        self.connected_components()
+            .into_iter()
+            .map(|component| {
+                let component_type = self.component_type(&component);
+                (component, component_type)
+            })
+            .collect()
+    }
+
+    fn component_type(&self, component: &[usize]) -> Option<CoxeterDiagramType> {
+        let rank = component.len();
+        let degree = |i: usize| self.neighbors(i).len();
+        let edge_count = component.iter().map(|&i| degree(i)).sum::<usize>() / 2;
+        let labels = component
+            .iter()
+            .flat_map(|&i| self.neighbors(i).into_iter().map(|(_, label)| label))
+            .collect_vec();
+        if rank == 1 {
+            return Some(CoxeterDiagramType::A(1));
+        }
+        if edge_count >= rank {
+            return (rank >= 3
+                && edge_count == rank
+                && component.iter().all(|&i| degree(i) == 2)
+                && labels.iter().all(|&label| label == CoxeterLabel::Finite(3)))
+            .then_some(CoxeterDiagramType::AffineA(rank - 1));
+        }
+        if rank == 2 {
+            return Some(match labels[0] {
+                CoxeterLabel::Finite(3) => CoxeterDiagramType::A(2),
+                CoxeterLabel::Finite(4) => CoxeterDiagramType::B(2),
+                CoxeterLabel::Finite(6) => CoxeterDiagramType::G(2),
+                CoxeterLabel::Finite(label) => CoxeterDiagramType::I2(label),
+                CoxeterLabel::Infinite => CoxeterDiagramType::AffineI1,
+            });
+        }
+        // Walks from `start` away from `previous` until a leaf or a branch node is reached,
+        // collecting the labels on the way.
+        let arm = |previous: usize, start: usize, label: CoxeterLabel| {
+            let mut arm = vec![label];
+            let (mut previous, mut current) = (previous, start);
+            while degree(current) == 2 {
+                let (next, label) = self
+                    .neighbors(current)
+                    .into_iter()
+                    .find(|&(next, _)| next != previous)
+                    .unwrap();
+                arm.push(label);
+                (previous, current) = (current, next);
+            }
+            arm
+        };
+        let is_simply_laced =
+            |arm: &[CoxeterLabel]| arm.iter().all(|&label| label == CoxeterLabel::Finite(3));
+        let branch_nodes = component
+            .iter()
+            .copied()
+            .filter(|&i| degree(i) >= 3)
+            .collect_vec();
+        match branch_nodes.as_slice() {
+            [] => {
+                let leaf = component.iter().copied().find(|&i| degree(i) == 1)?;
+                let (next, label) = self.neighbors(leaf)[0];
+                let path = arm(leaf, next, label);
+                let reversed_path = path.iter().rev().copied().collect_vec();
+                let is_path = |pattern: &[u64]| {
+                    [&path, &reversed_path].into_iter().any(|path| {
+                        path.iter()
+                            .copied()
+                            .eq(pattern.iter().map(|&label| CoxeterLabel::Finite(label)))
+                    })
+                };
+                let with_end = |label: u64| {
+                    std::iter::repeat_n(3, rank - 2)
+                        .chain([label])
+                        .collect_vec()
+                };
+                if is_simply_laced(&path) {
+                    Some(CoxeterDiagramType::A(rank))
+                } else if is_path(&with_end(4)) {
+                    Some(CoxeterDiagramType::B(rank))
+                } else if is_path(&[3, 4, 3]) {
+                    Some(CoxeterDiagramType::F(4))
+                } else if is_path(&[3, 3, 4, 3]) {
+                    Some(CoxeterDiagramType::AffineF(4))
+                } else if rank <= 4 && is_path(&with_end(5)) {
+                    Some(CoxeterDiagramType::H(rank))
+                } else if is_path(&[3, 6]) {
+                    Some(CoxeterDiagramType::AffineG(2))
+                } else if is_path(
+                    &std::iter::once(4)
+                        .chain(std::iter::repeat_n(3, rank - 3))
+                        .chain([4])
+                        .collect_vec(),
+                ) {
+                    Some(CoxeterDiagramType::AffineC(rank - 1))
+                } else {
+                    None
+                }
+            }
+            &[branch_node] => {
+                let arms = self
+                    .neighbors(branch_node)
+                    .into_iter()
+                    .map(|(next, label)| arm(branch_node, next, label))
+                    .sorted_by_key(|arm| (!is_simply_laced(arm), arm.len()))
+                    .collect_vec();
+                if arms.iter().all(|arm| is_simply_laced(arm)) {
+                    match arms.iter().map(Vec::len).collect_vec().as_slice() {
+                        [1, 1, _] => Some(CoxeterDiagramType::D(rank)),
+                        [1, 2, 2] => Some(CoxeterDiagramType::E(6)),
+                        [1, 2, 3] => Some(CoxeterDiagramType::E(7)),
+                        [1, 2, 4] => Some(CoxeterDiagramType::E(8)),
+                        [2, 2, 2] => Some(CoxeterDiagramType::AffineE(6)),
+                        [1, 3, 3] => Some(CoxeterDiagramType::AffineE(7)),
+                        [1, 2, 5] => Some(CoxeterDiagramType::AffineE(8)),
+                        [1, 1, 1, 1] => Some(CoxeterDiagramType::AffineD(4)),
+                        _ => None,
+                    }
+                } else {
+                    match arms.as_slice() {
+                        [arm0, arm1, arm2]
+                            if arm0.len() == 1
+                                && arm1.len() == 1
+                                && arm2.last() == Some(&CoxeterLabel::Finite(4))
+                                && is_simply_laced(&arm2[..arm2.len() - 1]) =>
+                        {
+                            Some(CoxeterDiagramType::AffineB(rank - 1))
+                        }
+                        _ => None,
+                    }
+                }
+            }
+            &[branch_node0, branch_node1] => (rank >= 6
+                && labels.iter().all(|&label| label == CoxeterLabel::Finite(3))
+                && [branch_node0, branch_node1].into_iter().all(|branch_node| {
+                    degree(branch_node) == 3
+                        && self
+                            .neighbors(branch_node)
+                            .into_iter()
+                            .filter(|&(next, _)| degree(next) == 1)
+                            .count()
+                            == 2
+                }))
+            .then_some(CoxeterDiagramType::AffineD(rank - 1)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn cyclotomic_ring(
         &self,
     ) -> RingValue<CyclotomicRingBase<feanor_math::primitive_int::StaticRing<i64>>> {
         RingValue::from(CyclotomicRingBase::new(
@@ -77,7 +401,7 @@ impl CoxeterDiagram {
         ))
     }
 
-    fn matrix_ring(
+    pub(crate) fn matrix_ring(
         &self,
     ) -> RingValue<
         SquareMatrixRingBase<
@@ -99,15 +423,13 @@ impl CoxeterDiagram {
         let cr = self.cyclotomic_ring();
         OwnedMatrix::from_fn(rank, rank, |i, j| {
             self.get_edge(i, j)
-                .map(|&label| {
-                    if label == 3 {
-                        cr.neg_one()
-                    } else {
-                        cr.negate(cr.add(
-                            cyclotomic_root_of_unity(&cr, 2 * label, 1),
-                            cyclotomic_root_of_unity(&cr, 2 * label, 2 * label - 1),
-                        ))
-                    }
+                .map(|&label| match label {
+                    CoxeterLabel::Finite(3) => cr.neg_one(),
+                    CoxeterLabel::Finite(label) => cr.negate(cr.add(
+                        cyclotomic_root_of_unity(&cr, 2 * label, 1),
+                        cyclotomic_root_of_unity(&cr, 2 * label, 2 * label - 1),
+                    )),
+                    CoxeterLabel::Infinite => cr.get_ring().from_int(-2),
                 })
                 .unwrap_or_else(|| {
                     if i == j {
@@ -119,35 +441,88 @@ impl CoxeterDiagram {
         })
     }
 
+    pub(crate) fn numeric_schlafli_matrix(&self) -> Vec<Vec<f64>> {
+        numeric_matrix(
+            &self.cyclotomic_ring(),
+            &self.schlafli_matrix(),
+            self.rank(),
+        )
+    }
+
     pub fn coxeter_group_type(&self) -> CoxeterGroupType {
-        const CC: feanor_math::rings::float_complex::Complex64 =
-            feanor_math::rings::float_complex::Complex64::RING;
         let rank = self.rank();
-        let cr = self.cyclotomic_ring();
-        let schlafli_matrix = self.schlafli_matrix();
-        let mut coxeter_group_type = CoxeterGroupType::Elliptic;
-        (1..=rank).for_each(|n| {
-            let numeric = CC.re(cyclotomic_numeric_embed_into(
-                &cr,
-                determinant(
-                    &RingValue::from(SquareMatrixRingBase::new(cr.clone(), n)),
-                    OwnedMatrix::from_fn(n, n, |i, j| cr.clone_el(schlafli_matrix.at(i, j))),
-                ),
-            ));
-            if numeric > 16.0 * std::f64::EPSILON {
-            } else if numeric >= -16.0 * std::f64::EPSILON {
-                if coxeter_group_type == CoxeterGroupType::Elliptic {
-                    coxeter_group_type = CoxeterGroupType::Parabolic;
-                }
+        let signature = self.signature();
+        if signature.negative == 0 {
+            return if signature.zero == 0 {
+                CoxeterGroupType::Elliptic
             } else {
-                if coxeter_group_type == CoxeterGroupType::Elliptic
-                    || coxeter_group_type == CoxeterGroupType::Parabolic
-                {
-                    coxeter_group_type = CoxeterGroupType::Hyperbolic;
-                }
-            }
-        });
-        coxeter_group_type
+                CoxeterGroupType::Parabolic
+            };
+        }
+        if signature.negative > 1 || signature.zero > 0 {
+            return CoxeterGroupType::Indefinite;
+        }
+        // Subdiagrams of positive semidefinite diagrams are positive semidefinite, so checking the
+        // maximal proper subdiagrams suffices.
+        let subdiagram_signatures = (0..rank)
+            .map(|i| {
+                self.subdiagram(&(0..rank).filter(|&j| j != i).collect_vec())
+                    .signature()
+            })
+            .collect_vec();
+        if subdiagram_signatures
+            .iter()
+            .all(|signature| signature.negative == 0 && signature.zero == 0)
+        {
+            CoxeterGroupType::CompactHyperbolic
+        } else if subdiagram_signatures
+            .iter()
+            .all(|signature| signature.negative == 0)
+        {
+            CoxeterGroupType::Hyperbolic
+        } else {
+            CoxeterGroupType::Lorentzian
+        }
+    }
+
+    pub fn component_group_types(&self) -> Vec<(Vec<usize>, CoxeterGroupType)> {
+        self.connected_components()
+            .into_iter()
+            .map(|component| {
+                let coxeter_group_type = self.subdiagram(&component).coxeter_group_type();
+                (component, coxeter_group_type)
+            })
+            .collect()
+    }
+
+    pub fn signature(&self) -> Signature {
+        let rank = self.rank();
+        let cr = self.cyclotomic_ring();
+        let pr = DensePolyRing::new(cr.clone(), "lambda");
+        let characteristic_polynomial =
+            characteristic_polynomial(&self.matrix_ring(), &pr, self.schlafli_matrix());
+        // The Schlafli matrix is real symmetric, so its characteristic polynomial has only real
+        // roots and Descartes' rule of signs counts the positive ones exactly.
+        let signs = (0..=rank)
+            .map(|degree| {
+                cyclotomic_sign(&cr, pr.coefficient_at(&characteristic_polynomial, degree))
+            })
+            .collect_vec();
+        let zero = signs
+            .iter()
+            .position(|&sign| sign != std::cmp::Ordering::Equal)
+            .unwrap();
+        let positive = signs
+            .iter()
+            .filter(|&&sign| sign != std::cmp::Ordering::Equal)
+            .tuple_windows()
+            .filter(|(lhs, rhs)| lhs != rhs)
+            .count();
+        Signature {
+            positive,
+            zero,
+            negative: rank - zero - positive,
+        }
     }
 
     pub fn coxeter_element(
@@ -184,12 +559,109 @@ impl CoxeterDiagram {
         )
     }
 
-    pub fn coxeter_group_info(&self) -> CoxeterGroupInfo {
+    // The product `s_o0 s_o1 ...` of the simple reflections in the given order of nodes, computed
+    // by renumbering the nodes, or `None` unless the ordering is a permutation of the nodes.
+    pub fn coxeter_element_for_ordering(&self, ordering: &[usize]) -> Option<Matrix> {
         let rank = self.rank();
+        if !ordering.iter().sorted().copied().eq(0..rank) {
+            return None;
+        }
+        let cr = self.cyclotomic_ring();
+        let coxeter_element = self.subdiagram(ordering).coxeter_element();
+        let mut positions = vec![0; rank];
+        ordering
+            .iter()
+            .enumerate()
+            .for_each(|(position, &i)| positions[i] = position);
+        Some(OwnedMatrix::from_fn(rank, rank, |i, j| {
+            cr.clone_el(coxeter_element.at(positions[i], positions[j]))
+        }))
+    }
+
+    // The Coxeter element in which `s_i` comes before `s_j` for every pair `(i, j)` of the
+    // orientation, or `None` if the orientation has a cycle or a node out of range. Only the
+    // orientation of edges matters, as reflections of nonadjacent nodes commute.
+    pub fn coxeter_element_for_orientation(
+        &self,
+        orientation: &[(usize, usize)],
+    ) -> Option<Matrix> {
+        let rank = self.rank();
+        if orientation.iter().any(|&(i, j)| i.max(j) >= rank) {
+            return None;
+        }
+        let mut in_degrees = vec![0; rank];
+        orientation.iter().for_each(|&(_, j)| in_degrees[j] += 1);
+        let mut ordering = Vec::with_capacity(rank);
+        while let Some(i) = (0..rank).find(|&i| in_degrees[i] == 0 && !ordering.contains(&i)) {
+            ordering.push(i);
+            orientation
+                .iter()
+                .filter(|&&(source, _)| source == i)
+                .for_each(|&(_, j)| in_degrees[j] -= 1);
+        }
+        // A cycle leaves some nodes out of the ordering.
+        self.coxeter_element_for_ordering(&ordering)
+    }
+
+    // The bipartite Coxeter element `c+ c-`, where `c+` and `c-` are the products of the
+    // reflections in the two color classes, the first node of each component colored `+`. Only
+    // diagrams without odd cycles are bipartite.
+    pub fn bipartite_coxeter_element(&self) -> Option<Matrix> {
+        let rank = self.rank();
+        let mut colors = vec![None; rank];
+        for component in self.connected_components() {
+            colors[component[0]] = Some(false);
+            let mut stack = vec![component[0]];
+            while let Some(i) = stack.pop() {
+                for (j, _) in self.neighbors(i) {
+                    match colors[j] {
+                        None => {
+                            colors[j] = colors[i].map(|color| !color);
+                            stack.push(j);
+                        }
+                        Some(color) if Some(color) == colors[i] => return None,
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        self.coxeter_element_for_ordering(&(0..rank).sorted_by_key(|&i| colors[i]).collect_vec())
+    }
+
+    // Finite groups are the products of the groups of elliptic components, with the Coxeter number
+    // being the order of the Coxeter element. Returns `None` for infinite groups.
+    pub fn coxeter_group_info(&self) -> Option<CoxeterGroupInfo> {
+        let infos = self
+            .connected_components()
+            .into_iter()
+            .map(|component| {
+                let subdiagram = self.subdiagram(&component);
+                (subdiagram.coxeter_group_type() == CoxeterGroupType::Elliptic)
+                    .then(|| subdiagram.irreducible_coxeter_group_info())
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let exponents = infos
+            .iter()
+            .flat_map(|info| info.exponents.iter().copied())
+            .sorted()
+            .collect_vec();
+        let degrees = exponents.iter().map(|&exponent| exponent + 1).collect_vec();
+        Some(CoxeterGroupInfo {
+            coxeter_number: infos
+                .iter()
+                .map(|info| info.coxeter_number as usize)
+                .fold(1, lcm) as u64,
+            order: degrees.iter().product(),
+            exponents,
+            degrees,
+        })
+    }
+
+    fn irreducible_coxeter_group_info(&self) -> CoxeterGroupInfo {
         let cr = self.cyclotomic_ring();
         let mr = self.matrix_ring();
-        let pr = feanor_math::rings::poly::dense_poly::DensePolyRing::new(cr.clone(), "lambda");
-        let mut coxeter_element = self.coxeter_element();
+        let pr = DensePolyRing::new(cr.clone(), "lambda");
+        let coxeter_element = self.coxeter_element();
         let coxeter_number = std::iter::successors(Some(mr.one()), |matrix| {
             Some(mr.mul_ref(&coxeter_element, matrix))
         })
@@ -198,28 +670,10 @@ impl CoxeterDiagram {
         .find(|(_, matrix)| mr.is_one(matrix))
         .map(|(order, _)| order)
         .unwrap() as u64;
-        let characteristic_polynomial = determinant(
-            &RingValue::from(SquareMatrixRingBase::new(pr.clone(), rank)),
-            OwnedMatrix::from_fn(rank, rank, |i, j| {
-                if i == j {
-                    pr.from_terms([
-                        (
-                            cr.negate(std::mem::replace(coxeter_element.at_mut(i, j), cr.zero())),
-                            0,
-                        ),
-                        (cr.one(), 1),
-                    ])
-                } else {
-                    pr.from_terms([(
-                        cr.negate(std::mem::replace(coxeter_element.at_mut(i, j), cr.zero())),
-                        0,
-                    )])
-                }
-            }),
-        );
+        let characteristic_polynomial = characteristic_polynomial(&mr, &pr, coxeter_element);
         let exponents = (0..coxeter_number)
             .scan(characteristic_polynomial, |polynomial, exponent| {
-                (!pr.is_one(&polynomial)).then(|| {
+                (!pr.is_one(polynomial)).then(|| {
                     let monomial = pr.from_terms([
                         (
                             cr.negate(cyclotomic_root_of_unity(&cr, coxeter_number, exponent)),
@@ -250,6 +704,286 @@ impl CoxeterDiagram {
         }
     }
 
+    pub fn coxeter_polynomial(&self) -> CoxeterPolynomial {
+        const CC: Complex64 = Complex64::RING;
+        const ZZ: BigIntRing = BigIntRing::RING;
+        let cr = self.cyclotomic_ring();
+        let pr = DensePolyRing::new(cr.clone(), "lambda");
+        let characteristic_polynomial =
+            characteristic_polynomial(&self.matrix_ring(), &pr, self.coxeter_element());
+        let coefficients = (0..=self.rank())
+            .map(|degree| cr.clone_el(pr.coefficient_at(&characteristic_polynomial, degree)))
+            .collect_vec();
+        let numeric_coefficients = coefficients
+            .iter()
+            .map(|coefficient| CC.re(cyclotomic_numeric_embed_into(&cr, cr.clone_el(coefficient))))
+            .collect_vec();
+        let reduced = |coefficient: &El<CyclotomicRing>| {
+            cr.get_ring()
+                .reduced_coefficients(coefficient, coefficient.len())
+        };
+        // A rational integer is exactly an element whose reduced form is a constant.
+        let to_integer = |coefficient: &El<CyclotomicRing>| {
+            let reduced = reduced(coefficient);
+            reduced[1..].iter().all(|&c| c == 0).then_some(reduced[0])
+        };
+        let integer_polynomial = coefficients
+            .iter()
+            .map(to_integer)
+            .collect::<Option<Vec<_>>>();
+        let is_integral = integer_polynomial.is_some();
+        // The coefficients are real, so their conjugates are given by `zeta_N -> zeta_N^k` over
+        // the units `k` modulo `N` up to sign.
+        let integer_polynomial = integer_polynomial.unwrap_or_else(|| {
+            let order = coefficients
+                .iter()
+                .map(|coefficient| coefficient.len())
+                .fold(1, lcm);
+            let norm = pr.prod(
+                (1..order.max(2))
+                    .filter(|&k| gcd(k, order) == 1 && 2 * k <= order.max(2))
+                    .map(|k| {
+                        pr.from_terms(coefficients.iter().enumerate().map(
+                            |(degree, coefficient)| {
+                                let mut conjugate = vec![0; order];
+                                coefficient.iter().enumerate().for_each(|(index, &c)| {
+                                    conjugate[index * order / coefficient.len() * k % order] += c;
+                                });
+                                (conjugate, degree)
+                            },
+                        ))
+                    }),
+            );
+            // The norm is fixed by the Galois group, so its coefficients are rational integers.
+            (0..=pr.degree(&norm).unwrap())
+                .map(|degree| {
+                    let reduced = reduced(pr.coefficient_at(&norm, degree));
+                    debug_assert!(reduced[1..].iter().all(|&c| c == 0));
+                    reduced[0]
+                })
+                .collect_vec()
+        });
+        let zzx = DensePolyRing::new(ZZ, "x");
+        let factors = poly_factor_integer(
+            &zzx,
+            zzx.from_terms(
+                integer_polynomial
+                    .iter()
+                    .enumerate()
+                    .map(|(degree, &c)| (int_cast(c, ZZ, StaticRing::<i64>::RING), degree)),
+            ),
+            DontObserve,
+        )
+        .into_iter()
+        .map(|(factor, multiplicity)| {
+            let degree = zzx.degree(&factor).unwrap();
+            let sign = if ZZ.is_neg(zzx.lc(&factor).unwrap()) {
+                -1
+            } else {
+                1
+            };
+            (
+                (0..=degree)
+                    .map(|degree| {
+                        sign * int_cast(
+                            ZZ.clone_el(zzx.coefficient_at(&factor, degree)),
+                            StaticRing::<i64>::RING,
+                            ZZ,
+                        )
+                    })
+                    .collect_vec(),
+                multiplicity,
+            )
+        })
+        .sorted()
+        .collect_vec();
+        // By Kronecker's theorem the monic irreducible factors with all roots on the unit circle
+        // are the cyclotomic polynomials `Phi_k`, and `phi(k) >= sqrt(k / 2)` bounds the order.
+        let cyclotomic_order = |factor: &Vec<i64>| {
+            let degree = factor.len() - 1;
+            (1..=2 * degree * degree + 2).find(|&order| cyclotomic_coefficients(order) == *factor)
+        };
+        let (cyclotomic_factors, non_cyclotomic_factors): (Vec<_>, Vec<(Vec<i64>, usize)>) =
+            factors
+                .iter()
+                .partition_map(|(factor, multiplicity)| match cyclotomic_order(factor) {
+                    Some(order) => itertools::Either::Left((order as u64, *multiplicity)),
+                    None => itertools::Either::Right((factor.clone(), *multiplicity)),
+                });
+        let cyclotomic_factors = cyclotomic_factors.into_iter().sorted().collect_vec();
+        // The roots of the Coxeter polynomial are located among the simple roots of the factors.
+        let evaluate = |coefficients: &[f64], z: Complex64El| {
+            coefficients.iter().rev().fold(CC.zero(), |value, &c| {
+                CC.add(CC.mul(value, z), CC.from_f64(c))
+            })
+        };
+        let factor_roots = non_cyclotomic_factors
+            .iter()
+            .map(|(factor, _)| polynomial_roots(&factor.iter().map(|&c| c as f64).collect_vec()))
+            .collect_vec();
+        let leading = factor_roots
+            .iter()
+            .enumerate()
+            .flat_map(|(index, roots)| roots.iter().map(move |&root| (index, root)))
+            .filter(|&(_, root)| {
+                let scale = numeric_coefficients
+                    .iter()
+                    .enumerate()
+                    .map(|(degree, c)| c.abs() * CC.abs(root).powi(degree as i32))
+                    .sum::<f64>();
+                CC.abs(evaluate(&numeric_coefficients, root)) < 1e-6 * scale
+            })
+            .max_by(|(_, lhs), (_, rhs)| CC.abs(*lhs).total_cmp(&CC.abs(*rhs)))
+            .filter(|&(_, root)| CC.abs(root) > 1.0 + 1e-9);
+        let (spectral_radius, leading_root) = match leading {
+            None => (1.0, LeadingRoot::RootOfUnity),
+            Some((index, root)) => {
+                let conjugates = factor_roots[index]
+                    .iter()
+                    .filter(|&&conjugate| !CC.is_absolute_approx_eq(conjugate, root, 1e-9))
+                    .map(|&conjugate| CC.abs(conjugate))
+                    .collect_vec();
+                let leading_root =
+                    if CC.im(root).abs() > 1e-9 || conjugates.iter().any(|&abs| abs > 1.0 + 1e-9) {
+                        LeadingRoot::Other
+                    } else if conjugates.iter().any(|&abs| abs > 1.0 - 1e-9) {
+                        LeadingRoot::Salem
+                    } else {
+                        LeadingRoot::Pisot
+                    };
+                (CC.abs(root), leading_root)
+            }
+        };
+        CoxeterPolynomial {
+            integer_polynomial,
+            is_integral,
+            cyclotomic_factors,
+            non_cyclotomic_factors,
+            spectral_radius,
+            leading_root,
+        }
+    }
+
+    // For a finite group, the length of the longest element and the multiplicities of the
+    // cyclotomic polynomials `Phi_k` in `W(t) = prod_i [d_i]_t`. Degrees of components are cached
+    // by their node sets.
+    fn poincare_factors(
+        &self,
+        nodes: &[usize],
+        component_degrees: &mut HashMap<Vec<usize>, Option<Vec<u64>>>,
+    ) -> Option<(usize, BTreeMap<usize, usize>)> {
+        let subdiagram = self.subdiagram(nodes);
+        let degrees = subdiagram
+            .connected_components()
+            .into_iter()
+            .map(|component| {
+                component_degrees
+                    .entry(component.iter().map(|&i| nodes[i]).collect())
+                    .or_insert_with(|| {
+                        let component_diagram = subdiagram.subdiagram(&component);
+                        component_diagram
+                            .coxeter_group_info()
+                            .map(|info| info.degrees)
+                    })
+                    .clone()
+            })
+            .collect::<Option<Vec<_>>>()?
+            .concat();
+        let mut multiplicities = BTreeMap::new();
+        degrees.iter().for_each(|&degree| {
+            (2..=degree as usize)
+                .filter(|&order| (degree as usize).is_multiple_of(order))
+                .for_each(|order| *multiplicities.entry(order).or_insert(0) += 1);
+        });
+        Some((
+            degrees.iter().map(|&degree| degree as usize - 1).sum(),
+            multiplicities,
+        ))
+    }
+
+    // Finite groups have `W(t) = prod_i [d_i]_t`. Otherwise Steinberg's formula
+    // `1 / W(t) = sum_J (-1)^|J| / W_J(1/t) = sum_J (-1)^|J| t^N_J / W_J(t)` over the subsets `J`
+    // generating finite subgroups, with `N_J` the length of their longest element, is put over
+    // the least common multiple of the `W_J(t)`.
+    pub fn growth_series(&self) -> GrowthSeries {
+        let rank = self.rank();
+        let pr = DensePolyRing::new(StaticRing::<i64>::RING, "t");
+        let cyclotomic_polynomial = |order: usize| {
+            pr.from_terms(
+                cyclotomic_coefficients(order)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(degree, coefficient)| (coefficient, degree)),
+            )
+        };
+        let product =
+            |multiplicities: &BTreeMap<usize, usize>| {
+                pr.prod(multiplicities.iter().map(|(&order, &multiplicity)| {
+                    pr.pow(cyclotomic_polynomial(order), multiplicity)
+                }))
+            };
+        let coefficients = |polynomial: &El<DensePolyRing<StaticRing<i64>>>| {
+            (0..=pr.degree(polynomial).unwrap())
+                .map(|degree| *pr.coefficient_at(polynomial, degree))
+                .collect_vec()
+        };
+        let mut component_degrees = HashMap::new();
+        if let Some((_, multiplicities)) =
+            self.poincare_factors(&(0..rank).collect_vec(), &mut component_degrees)
+        {
+            return GrowthSeries {
+                numerator: coefficients(&product(&multiplicities)),
+                denominator: vec![1],
+            };
+        }
+        let factors = (0..rank)
+            .powerset()
+            .filter_map(|nodes| {
+                self.poincare_factors(&nodes, &mut component_degrees)
+                    .map(|(length, multiplicities)| (nodes.len(), length, multiplicities))
+            })
+            .collect_vec();
+        let mut lcm_multiplicities = BTreeMap::new();
+        factors.iter().for_each(|(_, _, multiplicities)| {
+            multiplicities.iter().for_each(|(&order, &multiplicity)| {
+                let lcm_multiplicity = lcm_multiplicities.entry(order).or_insert(0);
+                *lcm_multiplicity = multiplicity.max(*lcm_multiplicity);
+            });
+        });
+        let mut numerator = product(&lcm_multiplicities);
+        let mut denominator = pr.sum(factors.iter().map(|(size, length, multiplicities)| {
+            let cofactor = lcm_multiplicities
+                .iter()
+                .map(|(&order, &multiplicity)| {
+                    (
+                        order,
+                        multiplicity - multiplicities.get(&order).copied().unwrap_or(0),
+                    )
+                })
+                .collect();
+            pr.mul(
+                pr.from_terms([(if size % 2 == 0 { 1 } else { -1 }, *length)]),
+                product(&cofactor),
+            )
+        }));
+        lcm_multiplicities
+            .iter()
+            .for_each(|(&order, &multiplicity)| {
+                let cyclotomic = cyclotomic_polynomial(order);
+                (0..multiplicity).for_each(|_| {
+                    let (quo, rem) = pr.div_rem_monic(pr.clone_el(&denominator), &cyclotomic);
+                    if pr.is_zero(&rem) {
+                        denominator = quo;
+                        numerator = pr.div_rem_monic(pr.clone_el(&numerator), &cyclotomic).0;
+                    }
+                });
+            });
+        GrowthSeries {
+            numerator: coefficients(&numerator),
+            denominator: coefficients(&denominator),
+        }
+    }
+
     pub fn enumerate_trees_by_rank_and_depth(
     ) -> impl Iterator<Item = (usize, impl Iterator<Item = (usize, Vec<Self>)>)> {
         (1..).map(|rank| {
@@ -268,7 +1002,7 @@ impl CoxeterDiagram {
                                 tree.add_edge(
                                     petgraph::graph::NodeIndex::new(i),
                                     petgraph::graph::NodeIndex::new(j),
-                                    3_u64,
+                                    CoxeterLabel::Finite(3),
                                 );
                             });
                             tree
@@ -360,6 +1094,7 @@ where
 {
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CoxeterDiagramType {
     A(usize),
     B(usize),
@@ -370,64 +1105,153 @@ pub enum CoxeterDiagramType {
     G(usize),
     H(usize),
     I2(u64),
+    AffineA(usize),
+    AffineB(usize),
+    AffineC(usize),
+    AffineD(usize),
+    AffineE(usize),
+    AffineF(usize),
+    AffineG(usize),
+    AffineI1,
+}
+
+impl std::fmt::Display for CoxeterDiagramType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::A(rank) => write!(f, "A{rank}"),
+            Self::B(rank) => write!(f, "B{rank}"),
+            Self::C(rank) => write!(f, "C{rank}"),
+            Self::D(rank) => write!(f, "D{rank}"),
+            Self::E(rank) => write!(f, "E{rank}"),
+            Self::F(rank) => write!(f, "F{rank}"),
+            Self::G(rank) => write!(f, "G{rank}"),
+            Self::H(rank) => write!(f, "H{rank}"),
+            Self::I2(label) => write!(f, "I2({label})"),
+            Self::AffineA(n) => write!(f, "A\u{303}{n}"),
+            Self::AffineB(n) => write!(f, "B\u{303}{n}"),
+            Self::AffineC(n) => write!(f, "C\u{303}{n}"),
+            Self::AffineD(n) => write!(f, "D\u{303}{n}"),
+            Self::AffineE(n) => write!(f, "E\u{303}{n}"),
+            Self::AffineF(n) => write!(f, "F\u{303}{n}"),
+            Self::AffineG(n) => write!(f, "G\u{303}{n}"),
+            Self::AffineI1 => write!(f, "I\u{303}1"),
+        }
+    }
 }
 
 impl From<CoxeterDiagramType> for CoxeterDiagram {
     fn from(value: CoxeterDiagramType) -> Self {
         match value {
-            CoxeterDiagramType::A(rank @ 1..) => {
-                Self::new(rank, (0..rank).tuple_windows().map(|(i, j)| ((i, j), 3)))
-            }
+            CoxeterDiagramType::A(rank @ 1..) => Self::new(
+                rank,
+                (0..rank)
+                    .tuple_windows()
+                    .map(|(i, j)| ((i, j), CoxeterLabel::Finite(3))),
+            )
+            .unwrap(),
             CoxeterDiagramType::B(rank @ 2..) => Self::new(
                 rank,
                 (0..rank - 1)
                     .tuple_windows()
-                    .map(|(i, j)| ((i, j), 3))
-                    .chain([((rank - 2, rank - 1), 4)]),
-            ),
+                    .map(|(i, j)| ((i, j), CoxeterLabel::Finite(3)))
+                    .chain([((rank - 2, rank - 1), CoxeterLabel::Finite(4))]),
+            )
+            .unwrap(),
             CoxeterDiagramType::C(rank @ 2..) => Self::new(
                 rank,
                 (0..rank - 1)
                     .tuple_windows()
-                    .map(|(i, j)| ((i, j), 3))
-                    .chain([((rank - 2, rank - 1), 4)]),
-            ),
+                    .map(|(i, j)| ((i, j), CoxeterLabel::Finite(3)))
+                    .chain([((rank - 2, rank - 1), CoxeterLabel::Finite(4))]),
+            )
+            .unwrap(),
             CoxeterDiagramType::D(rank @ 3..) => Self::new(
                 rank,
                 (0..rank - 2)
                     .tuple_windows()
-                    .map(|(i, j)| ((i, j), 3))
-                    .chain([((rank - 3, rank - 2), 3), ((rank - 3, rank - 1), 3)]),
-            ),
+                    .map(|(i, j)| ((i, j), CoxeterLabel::Finite(3)))
+                    .chain([
+                        ((rank - 3, rank - 2), CoxeterLabel::Finite(3)),
+                        ((rank - 3, rank - 1), CoxeterLabel::Finite(3)),
+                    ]),
+            )
+            .unwrap(),
             CoxeterDiagramType::E(rank @ 4..) => Self::new(
                 rank,
                 (2..rank)
                     .tuple_windows()
-                    .map(|(i, j)| ((i, j), 3))
-                    .chain([((0, 2), 3), ((1, 3), 3)]),
-            ),
+                    .map(|(i, j)| ((i, j), CoxeterLabel::Finite(3)))
+                    .chain([
+                        ((0, 2), CoxeterLabel::Finite(3)),
+                        ((1, 3), CoxeterLabel::Finite(3)),
+                    ]),
+            )
+            .unwrap(),
             CoxeterDiagramType::F(rank @ 3..) => Self::new(
                 rank,
                 (0..rank - 2)
                     .tuple_windows()
-                    .map(|(i, j)| ((i, j), 3))
-                    .chain([((rank - 3, rank - 2), 4), ((rank - 2, rank - 1), 3)]),
-            ),
+                    .map(|(i, j)| ((i, j), CoxeterLabel::Finite(3)))
+                    .chain([
+                        ((rank - 3, rank - 2), CoxeterLabel::Finite(4)),
+                        ((rank - 2, rank - 1), CoxeterLabel::Finite(3)),
+                    ]),
+            )
+            .unwrap(),
             CoxeterDiagramType::G(rank @ 2..) => Self::new(
                 rank,
                 (0..rank - 1)
                     .tuple_windows()
-                    .map(|(i, j)| ((i, j), 3))
-                    .chain([((rank - 2, rank - 1), 6)]),
-            ),
+                    .map(|(i, j)| ((i, j), CoxeterLabel::Finite(3)))
+                    .chain([((rank - 2, rank - 1), CoxeterLabel::Finite(6))]),
+            )
+            .unwrap(),
             CoxeterDiagramType::H(rank @ 2..) => Self::new(
                 rank,
                 (0..rank - 1)
                     .tuple_windows()
-                    .map(|(i, j)| ((i, j), 3))
-                    .chain([((rank - 2, rank - 1), 5)]),
-            ),
-            CoxeterDiagramType::I2(label @ 3..) => Self::new(2, [((0, 1), label)]),
+                    .map(|(i, j)| ((i, j), CoxeterLabel::Finite(3)))
+                    .chain([((rank - 2, rank - 1), CoxeterLabel::Finite(5))]),
+            )
+            .unwrap(),
+            CoxeterDiagramType::I2(label @ 3..) => {
+                Self::new(2, [((0, 1), CoxeterLabel::Finite(label))]).unwrap()
+            }
+            // Affine diagrams keep the numbering of their finite counterpart and append the
+            // extending node (the one usually labeled 0) as the last node.
+            CoxeterDiagramType::AffineA(1) | CoxeterDiagramType::AffineI1 => {
+                Self::new(2, [((0, 1), CoxeterLabel::Infinite)]).unwrap()
+            }
+            CoxeterDiagramType::AffineA(n @ 2..) => {
+                Self::from(CoxeterDiagramType::A(n)).extended([
+                    (0, CoxeterLabel::Finite(3)),
+                    (n - 1, CoxeterLabel::Finite(3)),
+                ])
+            }
+            CoxeterDiagramType::AffineB(n @ 3..) => {
+                Self::from(CoxeterDiagramType::B(n)).extended([(1, CoxeterLabel::Finite(3))])
+            }
+            CoxeterDiagramType::AffineC(n @ 2..) => {
+                Self::from(CoxeterDiagramType::C(n)).extended([(0, CoxeterLabel::Finite(4))])
+            }
+            CoxeterDiagramType::AffineD(n @ 4..) => {
+                Self::from(CoxeterDiagramType::D(n)).extended([(1, CoxeterLabel::Finite(3))])
+            }
+            CoxeterDiagramType::AffineE(6) => {
+                Self::from(CoxeterDiagramType::E(6)).extended([(1, CoxeterLabel::Finite(3))])
+            }
+            CoxeterDiagramType::AffineE(7) => {
+                Self::from(CoxeterDiagramType::E(7)).extended([(0, CoxeterLabel::Finite(3))])
+            }
+            CoxeterDiagramType::AffineE(8) => {
+                Self::from(CoxeterDiagramType::E(8)).extended([(7, CoxeterLabel::Finite(3))])
+            }
+            CoxeterDiagramType::AffineF(4) => {
+                Self::from(CoxeterDiagramType::F(4)).extended([(0, CoxeterLabel::Finite(3))])
+            }
+            CoxeterDiagramType::AffineG(2) => {
+                Self::from(CoxeterDiagramType::G(2)).extended([(1, CoxeterLabel::Finite(3))])
+            }
             _ => panic!(),
         }
     }
@@ -447,20 +1271,62 @@ impl From<DynkinDiagramType> for CoxeterDiagramType {
     }
 }
 
+// The roots of a squarefree real polynomial given by coefficients starting from the constant term,
+// found by the Durand-Kerner iteration.
+fn polynomial_roots(coefficients: &[f64]) -> Vec<Complex64El> {
+    const CC: Complex64 = Complex64::RING;
+    let degree = coefficients.len() - 1;
+    let leading = coefficients[degree];
+    let evaluate = |z: Complex64El| {
+        coefficients.iter().rev().fold(CC.zero(), |value, &c| {
+            CC.add(CC.mul(value, z), CC.from_f64(c / leading))
+        })
+    };
+    let seed = CC.add(
+        CC.from_f64(0.4),
+        CC.mul(CC.from_f64(0.9), CC.root_of_unity(1, 4)),
+    );
+    let mut roots = (0..degree).map(|index| CC.pow(seed, index)).collect_vec();
+    for _ in 0..1000 {
+        let mut change: f64 = 0.0;
+        (0..degree).for_each(|i| {
+            let denominator = CC.prod(
+                (0..degree)
+                    .filter(|&j| j != i)
+                    .map(|j| CC.sub(roots[i], roots[j])),
+            );
+            let step = CC.div(&evaluate(roots[i]), &denominator);
+            roots[i] = CC.sub(roots[i], step);
+            change = change.max(CC.abs(step));
+        });
+        if change < 1e-15 {
+            break;
+        }
+    }
+    roots
+}
+
 #[cfg(test)]
 mod test {
-    use super::{CoxeterDiagram, CoxeterDiagramType, CoxeterGroupInfo, CoxeterGroupType};
+    use super::{
+        CoxeterDiagram, CoxeterDiagramType, CoxeterGroupInfo, CoxeterGroupType, CoxeterLabel,
+        CoxeterPolynomial, GrowthSeries, LeadingRoot, Signature,
+    };
+    use crate::coxeter_group::CoxeterGroup;
+    use crate::square_matrix::characteristic_polynomial as characteristic_polynomial_of;
+    use feanor_math::{ring::RingStore, rings::poly::dense_poly::DensePolyRing};
+    use itertools::Itertools;
 
     #[test]
     fn test_a3() {
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::A(3)).coxeter_group_info(),
-            CoxeterGroupInfo {
+            Some(CoxeterGroupInfo {
                 coxeter_number: 4,
                 order: 24,
                 exponents: vec![1, 2, 3],
                 degrees: vec![2, 3, 4],
-            },
+            }),
         );
     }
 
@@ -468,12 +1334,12 @@ mod test {
     fn test_b3() {
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::B(3)).coxeter_group_info(),
-            CoxeterGroupInfo {
+            Some(CoxeterGroupInfo {
                 coxeter_number: 6,
                 order: 48,
                 exponents: vec![1, 3, 5],
                 degrees: vec![2, 4, 6],
-            },
+            }),
         );
     }
 
@@ -481,12 +1347,12 @@ mod test {
     fn test_d4() {
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::D(4)).coxeter_group_info(),
-            CoxeterGroupInfo {
+            Some(CoxeterGroupInfo {
                 coxeter_number: 6,
                 order: 192,
                 exponents: vec![1, 3, 3, 5],
                 degrees: vec![2, 4, 4, 6],
-            },
+            }),
         );
     }
 
@@ -494,12 +1360,12 @@ mod test {
     fn test_h3() {
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::H(3)).coxeter_group_info(),
-            CoxeterGroupInfo {
+            Some(CoxeterGroupInfo {
                 coxeter_number: 10,
                 order: 120,
                 exponents: vec![1, 5, 9],
                 degrees: vec![2, 6, 10],
-            },
+            }),
         );
     }
 
@@ -507,12 +1373,12 @@ mod test {
     fn test_h4() {
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::H(4)).coxeter_group_info(),
-            CoxeterGroupInfo {
+            Some(CoxeterGroupInfo {
                 coxeter_number: 30,
                 order: 14400,
                 exponents: vec![1, 11, 19, 29],
                 degrees: vec![2, 12, 20, 30],
-            },
+            }),
         );
     }
 
@@ -520,12 +1386,12 @@ mod test {
     fn test_g2() {
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::G(2)).coxeter_group_info(),
-            CoxeterGroupInfo {
+            Some(CoxeterGroupInfo {
                 coxeter_number: 6,
                 order: 12,
                 exponents: vec![1, 5],
                 degrees: vec![2, 6],
-            },
+            }),
         );
     }
 
@@ -533,12 +1399,12 @@ mod test {
     fn test_f4() {
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::F(4)).coxeter_group_info(),
-            CoxeterGroupInfo {
+            Some(CoxeterGroupInfo {
                 coxeter_number: 12,
                 order: 1152,
                 exponents: vec![1, 5, 7, 11],
                 degrees: vec![2, 6, 8, 12],
-            },
+            }),
         );
     }
 
@@ -546,12 +1412,12 @@ mod test {
     fn test_e6() {
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::E(6)).coxeter_group_info(),
-            CoxeterGroupInfo {
+            Some(CoxeterGroupInfo {
                 coxeter_number: 12,
                 order: 51840,
                 exponents: vec![1, 4, 5, 7, 8, 11],
                 degrees: vec![2, 5, 6, 8, 9, 12],
-            },
+            }),
         );
     }
 
@@ -559,12 +1425,12 @@ mod test {
     fn test_e7() {
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::E(7)).coxeter_group_info(),
-            CoxeterGroupInfo {
+            Some(CoxeterGroupInfo {
                 coxeter_number: 18,
                 order: 2903040,
                 exponents: vec![1, 5, 7, 9, 11, 13, 17],
                 degrees: vec![2, 6, 8, 10, 12, 14, 18],
-            },
+            }),
         );
     }
 
@@ -572,12 +1438,49 @@ mod test {
     fn test_e8() {
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::E(8)).coxeter_group_info(),
-            CoxeterGroupInfo {
+            Some(CoxeterGroupInfo {
                 coxeter_number: 30,
                 order: 696729600,
                 exponents: vec![1, 7, 11, 13, 17, 19, 23, 29],
                 degrees: vec![2, 8, 12, 14, 18, 20, 24, 30],
-            },
+            }),
+        );
+    }
+
+    #[test]
+    fn test_reducible_and_infinite() {
+        assert_eq!(
+            CoxeterDiagram::new(
+                5,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((2, 3), CoxeterLabel::Finite(4)),
+                ],
+            )
+            .unwrap()
+            .coxeter_group_info(),
+            Some(CoxeterGroupInfo {
+                coxeter_number: 12,
+                order: 96,
+                exponents: vec![1, 1, 1, 2, 3],
+                degrees: vec![2, 2, 2, 3, 4],
+            }),
+        );
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::AffineA(2)).coxeter_group_info(),
+            None,
+        );
+        assert_eq!(
+            CoxeterDiagram::new(
+                4,
+                [
+                    ((0, 1), CoxeterLabel::Finite(5)),
+                    ((2, 3), CoxeterLabel::Infinite),
+                ],
+            )
+            .unwrap()
+            .coxeter_group_info(),
+            None,
         );
     }
 
@@ -593,7 +1496,7 @@ mod test {
         );
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::H(5)).coxeter_group_type(),
-            CoxeterGroupType::Hyperbolic,
+            CoxeterGroupType::CompactHyperbolic,
         );
         assert_eq!(
             CoxeterDiagram::from(CoxeterDiagramType::G(2)).coxeter_group_type(),
@@ -620,4 +1523,704 @@ mod test {
             CoxeterGroupType::Hyperbolic,
         );
     }
+
+    #[test]
+    fn test_infinite_label() {
+        let affine_a1 = CoxeterDiagram::new(2, [((0, 1), CoxeterLabel::Infinite)]).unwrap();
+        assert_eq!(affine_a1.coxeter_group_type(), CoxeterGroupType::Parabolic);
+        let modular = CoxeterDiagram::new(
+            3,
+            [
+                ((0, 1), CoxeterLabel::Finite(3)),
+                ((1, 2), CoxeterLabel::Infinite),
+            ],
+        )
+        .unwrap();
+        assert_eq!(modular.coxeter_group_type(), CoxeterGroupType::Hyperbolic);
+        assert!(CoxeterDiagram::from(CoxeterDiagramType::I2(7)).is_subgraph_of(&affine_a1));
+        assert!(!affine_a1.is_subgraph_of(&CoxeterDiagram::from(CoxeterDiagramType::I2(7))));
+        assert!(affine_a1.is_subgraph_of(&modular));
+        let mut label = CoxeterLabel::Infinite;
+        label += 1;
+        assert_eq!(label, CoxeterLabel::Infinite);
+    }
+
+    #[test]
+    fn test_invalid_labels() {
+        [
+            vec![((0, 1), CoxeterLabel::Finite(0))],
+            vec![((0, 1), CoxeterLabel::Finite(1))],
+            vec![((0, 0), CoxeterLabel::Finite(3))],
+            vec![((0, 2), CoxeterLabel::Finite(3))],
+            vec![
+                ((0, 1), CoxeterLabel::Finite(3)),
+                ((1, 0), CoxeterLabel::Finite(4)),
+            ],
+        ]
+        .into_iter()
+        .for_each(|labels| assert!(CoxeterDiagram::new(2, labels).is_none()));
+        assert!(CoxeterDiagram::new(2, [((0, 1), CoxeterLabel::Finite(2))]).is_some());
+        assert_eq!(CoxeterLabel::try_from(1), Err(1));
+        assert_eq!(CoxeterLabel::try_from(5), Ok(CoxeterLabel::Finite(5)));
+    }
+
+    #[test]
+    fn test_component_types() {
+        let finite_types = [
+            CoxeterDiagramType::A(1),
+            CoxeterDiagramType::A(5),
+            CoxeterDiagramType::B(2),
+            CoxeterDiagramType::B(6),
+            CoxeterDiagramType::D(4),
+            CoxeterDiagramType::D(7),
+            CoxeterDiagramType::E(6),
+            CoxeterDiagramType::E(7),
+            CoxeterDiagramType::E(8),
+            CoxeterDiagramType::F(4),
+            CoxeterDiagramType::G(2),
+            CoxeterDiagramType::H(3),
+            CoxeterDiagramType::H(4),
+            CoxeterDiagramType::I2(7),
+        ];
+        finite_types.into_iter().for_each(|coxeter_diagram_type| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            assert_eq!(
+                coxeter_diagram.component_types(),
+                vec![(
+                    (0..coxeter_diagram.rank()).collect(),
+                    Some(coxeter_diagram_type)
+                )],
+            );
+        });
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::C(3)).component_types(),
+            vec![(vec![0, 1, 2], Some(CoxeterDiagramType::B(3)))],
+        );
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::E(9)).component_types(),
+            vec![((0..9).collect(), Some(CoxeterDiagramType::AffineE(8)))],
+        );
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::H(5)).component_types(),
+            vec![((0..5).collect(), None)],
+        );
+        let f = CoxeterLabel::Finite;
+        // H3 x A1 x I2(7), with the nodes interleaved and an explicit commuting label.
+        assert_eq!(
+            CoxeterDiagram::new(
+                6,
+                [
+                    ((0, 2), f(3)),
+                    ((2, 4), f(5)),
+                    ((1, 5), f(7)),
+                    ((3, 4), f(2)),
+                ],
+            )
+            .unwrap()
+            .component_types(),
+            vec![
+                (vec![0, 2, 4], Some(CoxeterDiagramType::H(3))),
+                (vec![1, 5], Some(CoxeterDiagramType::I2(7))),
+                (vec![3], Some(CoxeterDiagramType::A(1))),
+            ],
+        );
+        // Affine A3 x affine I1 x affine C2.
+        assert_eq!(
+            CoxeterDiagram::new(
+                9,
+                [
+                    ((0, 1), f(3)),
+                    ((1, 2), f(3)),
+                    ((2, 3), f(3)),
+                    ((3, 0), f(3)),
+                    ((4, 5), CoxeterLabel::Infinite),
+                    ((6, 7), f(4)),
+                    ((7, 8), f(4)),
+                ],
+            )
+            .unwrap()
+            .component_types(),
+            vec![
+                (vec![0, 1, 2, 3], Some(CoxeterDiagramType::AffineA(3))),
+                (vec![4, 5], Some(CoxeterDiagramType::AffineI1)),
+                (vec![6, 7, 8], Some(CoxeterDiagramType::AffineC(2))),
+            ],
+        );
+        // Affine B4, affine D4, affine D5, affine E6, affine F4 and affine G2.
+        [
+            (
+                vec![
+                    ((0, 2), f(3)),
+                    ((1, 2), f(3)),
+                    ((2, 3), f(3)),
+                    ((3, 4), f(4)),
+                ],
+                CoxeterDiagramType::AffineB(4),
+            ),
+            (
+                vec![
+                    ((0, 4), f(3)),
+                    ((1, 4), f(3)),
+                    ((2, 4), f(3)),
+                    ((3, 4), f(3)),
+                ],
+                CoxeterDiagramType::AffineD(4),
+            ),
+            (
+                vec![
+                    ((0, 2), f(3)),
+                    ((1, 2), f(3)),
+                    ((2, 3), f(3)),
+                    ((3, 4), f(3)),
+                    ((3, 5), f(3)),
+                ],
+                CoxeterDiagramType::AffineD(5),
+            ),
+            (
+                vec![
+                    ((0, 1), f(3)),
+                    ((1, 2), f(3)),
+                    ((2, 3), f(3)),
+                    ((3, 4), f(3)),
+                    ((2, 5), f(3)),
+                    ((5, 6), f(3)),
+                ],
+                CoxeterDiagramType::AffineE(6),
+            ),
+            (
+                vec![
+                    ((0, 1), f(3)),
+                    ((1, 2), f(4)),
+                    ((2, 3), f(3)),
+                    ((3, 4), f(3)),
+                ],
+                CoxeterDiagramType::AffineF(4),
+            ),
+            (
+                vec![((0, 1), f(6)), ((1, 2), f(3))],
+                CoxeterDiagramType::AffineG(2),
+            ),
+        ]
+        .into_iter()
+        .for_each(|(labels, coxeter_diagram_type)| {
+            let coxeter_diagram = CoxeterDiagram::new(
+                labels.iter().map(|&((_, j), _)| j + 1).max().unwrap(),
+                labels,
+            )
+            .unwrap();
+            assert_eq!(
+                coxeter_diagram.component_types(),
+                vec![(
+                    (0..coxeter_diagram.rank()).collect(),
+                    Some(coxeter_diagram_type)
+                )],
+            );
+        });
+    }
+
+    #[test]
+    fn test_affine() {
+        let affine_types = [
+            CoxeterDiagramType::AffineA(2),
+            CoxeterDiagramType::AffineA(5),
+            CoxeterDiagramType::AffineB(3),
+            CoxeterDiagramType::AffineB(5),
+            CoxeterDiagramType::AffineC(2),
+            CoxeterDiagramType::AffineC(5),
+            CoxeterDiagramType::AffineD(4),
+            CoxeterDiagramType::AffineD(6),
+            CoxeterDiagramType::AffineE(6),
+            CoxeterDiagramType::AffineE(7),
+            CoxeterDiagramType::AffineE(8),
+            CoxeterDiagramType::AffineF(4),
+            CoxeterDiagramType::AffineG(2),
+            CoxeterDiagramType::AffineI1,
+        ];
+        affine_types.into_iter().for_each(|coxeter_diagram_type| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            assert_eq!(
+                coxeter_diagram.coxeter_group_type(),
+                CoxeterGroupType::Parabolic,
+            );
+            assert_eq!(
+                coxeter_diagram.component_types(),
+                vec![(
+                    (0..coxeter_diagram.rank()).collect(),
+                    Some(coxeter_diagram_type)
+                )],
+            );
+        });
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::AffineA(1)).component_types(),
+            vec![(vec![0, 1], Some(CoxeterDiagramType::AffineI1))],
+        );
+    }
+
+    #[test]
+    fn test_coxeter_group_type_exact() {
+        let f = CoxeterLabel::Finite;
+        let triangle = |p: u64, q: u64, r: u64| {
+            CoxeterDiagram::new(3, [((0, 1), f(p)), ((1, 2), f(q)), ((0, 2), f(r))]).unwrap()
+        };
+        assert_eq!(
+            triangle(3, 6, 2).coxeter_group_type(),
+            CoxeterGroupType::Parabolic
+        );
+        assert_eq!(
+            triangle(4, 4, 2).coxeter_group_type(),
+            CoxeterGroupType::Parabolic
+        );
+        assert_eq!(
+            triangle(3, 3, 3).coxeter_group_type(),
+            CoxeterGroupType::Parabolic
+        );
+        assert_eq!(
+            triangle(3, 7, 2).coxeter_group_type(),
+            CoxeterGroupType::CompactHyperbolic
+        );
+        assert_eq!(
+            triangle(3, 5, 2).coxeter_group_type(),
+            CoxeterGroupType::Elliptic
+        );
+        assert_eq!(
+            triangle(1000, 2, 2).coxeter_group_type(),
+            CoxeterGroupType::Elliptic
+        );
+    }
+
+    #[test]
+    fn test_signature() {
+        let signature = |positive, zero, negative| Signature {
+            positive,
+            zero,
+            negative,
+        };
+        let f = CoxeterLabel::Finite;
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::H(4)).signature(),
+            signature(4, 0, 0),
+        );
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::AffineE(8)).signature(),
+            signature(8, 1, 0),
+        );
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::AffineI1).signature(),
+            signature(1, 1, 0),
+        );
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::E(10)).signature(),
+            signature(9, 0, 1),
+        );
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::H(5)).signature(),
+            signature(4, 0, 1),
+        );
+        assert_eq!(
+            CoxeterDiagram::new(
+                4,
+                [
+                    ((0, 1), CoxeterLabel::Infinite),
+                    ((0, 2), CoxeterLabel::Infinite),
+                    ((0, 3), CoxeterLabel::Infinite),
+                    ((1, 2), CoxeterLabel::Infinite),
+                    ((1, 3), CoxeterLabel::Infinite),
+                    ((2, 3), CoxeterLabel::Infinite),
+                ],
+            )
+            .unwrap()
+            .signature(),
+            signature(3, 0, 1),
+        );
+        assert_eq!(
+            CoxeterDiagram::new(
+                6,
+                [
+                    ((0, 1), f(3)),
+                    ((1, 2), f(7)),
+                    ((3, 4), f(3)),
+                    ((4, 5), f(7)),
+                ],
+            )
+            .unwrap()
+            .signature(),
+            signature(4, 0, 2),
+        );
+        assert_eq!(
+            CoxeterDiagram::new(
+                5,
+                [
+                    ((0, 1), f(3)),
+                    ((1, 2), f(7)),
+                    ((3, 4), CoxeterLabel::Infinite),
+                ],
+            )
+            .unwrap()
+            .signature(),
+            signature(3, 1, 1),
+        );
+    }
+
+    #[test]
+    fn test_coxeter_group_type_refined() {
+        let f = CoxeterLabel::Finite;
+        assert_eq!(
+            CoxeterDiagram::new(4, [((0, 1), f(5)), ((1, 2), f(3)), ((2, 3), f(5))])
+                .unwrap()
+                .coxeter_group_type(),
+            CoxeterGroupType::CompactHyperbolic,
+        );
+        assert_eq!(
+            CoxeterDiagram::new(4, [((0, 1), f(4)), ((1, 2), f(3)), ((2, 3), f(6))])
+                .unwrap()
+                .coxeter_group_type(),
+            CoxeterGroupType::Hyperbolic,
+        );
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::E(11)).coxeter_group_type(),
+            CoxeterGroupType::Lorentzian,
+        );
+        assert_eq!(
+            CoxeterDiagram::new(4, [((0, 1), f(3)), ((1, 2), f(7))],)
+                .unwrap()
+                .coxeter_group_type(),
+            CoxeterGroupType::Lorentzian,
+        );
+        assert_eq!(
+            CoxeterDiagram::new(
+                6,
+                [
+                    ((0, 1), f(3)),
+                    ((1, 2), f(7)),
+                    ((3, 4), f(3)),
+                    ((4, 5), f(7)),
+                ],
+            )
+            .unwrap()
+            .coxeter_group_type(),
+            CoxeterGroupType::Indefinite,
+        );
+        assert_eq!(
+            CoxeterDiagram::new(
+                5,
+                [
+                    ((0, 1), f(3)),
+                    ((1, 2), f(7)),
+                    ((3, 4), CoxeterLabel::Infinite),
+                ],
+            )
+            .unwrap()
+            .coxeter_group_type(),
+            CoxeterGroupType::Indefinite,
+        );
+    }
+
+    #[test]
+    fn test_component_group_types() {
+        let f = CoxeterLabel::Finite;
+        // The affine component comes first, so every leading principal minor past the first
+        // vanishes even though the (2, 3, 7) triangle group component is hyperbolic.
+        let coxeter_diagram = CoxeterDiagram::new(
+            5,
+            [
+                ((0, 1), CoxeterLabel::Infinite),
+                ((2, 3), f(3)),
+                ((3, 4), f(7)),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            coxeter_diagram.coxeter_group_type(),
+            CoxeterGroupType::Indefinite
+        );
+        assert_eq!(
+            coxeter_diagram.component_group_types(),
+            vec![
+                (vec![0, 1], CoxeterGroupType::Parabolic),
+                (vec![2, 3, 4], CoxeterGroupType::CompactHyperbolic),
+            ],
+        );
+        let coxeter_diagram = CoxeterDiagram::new(
+            6,
+            [
+                ((0, 5), f(5)),
+                ((5, 2), f(3)),
+                ((1, 3), f(3)),
+                ((3, 4), f(3)),
+                ((4, 1), f(3)),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            coxeter_diagram.coxeter_group_type(),
+            CoxeterGroupType::Parabolic
+        );
+        assert_eq!(
+            coxeter_diagram.component_group_types(),
+            vec![
+                (vec![0, 2, 5], CoxeterGroupType::Elliptic),
+                (vec![1, 3, 4], CoxeterGroupType::Parabolic),
+            ],
+        );
+        // Renumbering the nodes does not change the classification.
+        [
+            CoxeterDiagramType::E(10),
+            CoxeterDiagramType::H(5),
+            CoxeterDiagramType::AffineD(5),
+            CoxeterDiagramType::F(4),
+        ]
+        .into_iter()
+        .for_each(|coxeter_diagram_type| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            let rank = coxeter_diagram.rank();
+            assert_eq!(
+                coxeter_diagram
+                    .subdiagram(&(0..rank).rev().collect::<Vec<_>>())
+                    .coxeter_group_type(),
+                coxeter_diagram.coxeter_group_type(),
+            );
+            assert_eq!(
+                coxeter_diagram
+                    .subdiagram(&(0..rank).map(|i| (7 * i + 1) % rank).collect::<Vec<_>>())
+                    .coxeter_group_type(),
+                coxeter_diagram.coxeter_group_type(),
+            );
+        });
+    }
+
+    #[test]
+    fn test_coxeter_polynomial() {
+        // Affine groups have a Jordan block for the eigenvalue 1.
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::AffineE(8)).coxeter_polynomial(),
+            CoxeterPolynomial {
+                integer_polynomial: vec![1, 1, 0, -1, -1, -1, -1, 0, 1, 1],
+                is_integral: true,
+                cyclotomic_factors: vec![(1, 2), (2, 1), (3, 1), (5, 1)],
+                non_cyclotomic_factors: vec![],
+                spectral_radius: 1.0,
+                leading_root: LeadingRoot::RootOfUnity,
+            },
+        );
+        let coxeter_polynomial =
+            CoxeterDiagram::from(CoxeterDiagramType::H(3)).coxeter_polynomial();
+        assert!(!coxeter_polynomial.is_integral);
+        assert_eq!(coxeter_polynomial.cyclotomic_factors, vec![(2, 2), (10, 1)]);
+        // Lehmer's polynomial.
+        let coxeter_polynomial =
+            CoxeterDiagram::from(CoxeterDiagramType::E(10)).coxeter_polynomial();
+        assert_eq!(
+            coxeter_polynomial.non_cyclotomic_factors,
+            vec![(vec![1, 1, 0, -1, -1, -1, -1, -1, 0, 1, 1], 1)],
+        );
+        assert!((coxeter_polynomial.spectral_radius - 1.176280818).abs() < 1e-9);
+        assert_eq!(coxeter_polynomial.leading_root, LeadingRoot::Salem);
+        // The (3, 4, 4) triangle group.
+        let coxeter_polynomial = CoxeterDiagram::new(
+            3,
+            [
+                ((0, 1), CoxeterLabel::Finite(4)),
+                ((1, 2), CoxeterLabel::Finite(4)),
+                ((0, 2), CoxeterLabel::Finite(3)),
+            ],
+        )
+        .unwrap()
+        .coxeter_polynomial();
+        assert_eq!(coxeter_polynomial.integer_polynomial, vec![1, -4, -4, 1]);
+        assert_eq!(
+            coxeter_polynomial.non_cyclotomic_factors,
+            vec![(vec![1, -5, 1], 1)],
+        );
+        assert!((coxeter_polynomial.spectral_radius - (5.0 + 21f64.sqrt()) / 2.0).abs() < 1e-9);
+        assert_eq!(coxeter_polynomial.leading_root, LeadingRoot::Pisot);
+        // The (2, 3, 7) triangle group, whose coefficients lie in a cubic field.
+        let coxeter_polynomial = CoxeterDiagram::new(
+            3,
+            [
+                ((0, 1), CoxeterLabel::Finite(3)),
+                ((1, 2), CoxeterLabel::Finite(7)),
+            ],
+        )
+        .unwrap()
+        .coxeter_polynomial();
+        assert!(!coxeter_polynomial.is_integral);
+        assert_eq!(coxeter_polynomial.cyclotomic_factors, vec![(2, 3)]);
+        assert_eq!(coxeter_polynomial.leading_root, LeadingRoot::Salem);
+    }
+
+    #[test]
+    fn test_growth_series() {
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::A(3)).growth_series(),
+            GrowthSeries {
+                numerator: vec![1, 3, 5, 6, 5, 3, 1],
+                denominator: vec![1],
+            },
+        );
+        assert_eq!(
+            CoxeterDiagram::from(CoxeterDiagramType::AffineA(1)).growth_series(),
+            GrowthSeries {
+                numerator: vec![1, 1],
+                denominator: vec![1, -1],
+            },
+        );
+        // The reducible group A1 x A1 x I2(5).
+        let growth_series = CoxeterDiagram::new(4, [((2, 3), CoxeterLabel::Finite(5))])
+            .unwrap()
+            .growth_series();
+        assert_eq!(growth_series.numerator.iter().sum::<i64>(), 40);
+        assert_eq!(growth_series.coefficients(8), vec![1, 4, 7, 8, 8, 7, 4, 1]);
+        [
+            CoxeterDiagramType::B(4),
+            CoxeterDiagramType::E(6),
+            CoxeterDiagramType::F(4),
+            CoxeterDiagramType::H(4),
+        ]
+        .into_iter()
+        .for_each(|coxeter_diagram_type| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            assert_eq!(
+                coxeter_diagram
+                    .growth_series()
+                    .numerator
+                    .iter()
+                    .sum::<i64>() as u64,
+                coxeter_diagram.coxeter_group_info().unwrap().order,
+            );
+        });
+        // Infinite groups, checked against the numbers of elements of each length.
+        [
+            CoxeterDiagram::from(CoxeterDiagramType::AffineA(2)),
+            CoxeterDiagram::from(CoxeterDiagramType::AffineG(2)),
+            CoxeterDiagram::new(
+                3,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((1, 2), CoxeterLabel::Finite(7)),
+                ],
+            )
+            .unwrap(),
+            CoxeterDiagram::new(
+                3,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((1, 2), CoxeterLabel::Infinite),
+                ],
+            )
+            .unwrap(),
+            CoxeterDiagram::new(
+                4,
+                [
+                    ((0, 1), CoxeterLabel::Finite(5)),
+                    ((1, 2), CoxeterLabel::Finite(3)),
+                    ((2, 3), CoxeterLabel::Finite(5)),
+                ],
+            )
+            .unwrap(),
+        ]
+        .into_iter()
+        .for_each(|coxeter_diagram| {
+            let group = CoxeterGroup::new(coxeter_diagram.clone());
+            let counts = (0..8)
+                .map(|length| group.elements_up_to_length(length).len() as i64)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                coxeter_diagram.growth_series().coefficients(8),
+                std::iter::once(1)
+                    .chain(counts.windows(2).map(|window| window[1] - window[0]))
+                    .collect::<Vec<_>>(),
+            );
+        });
+    }
+
+    #[test]
+    fn test_coxeter_element_orderings() {
+        [
+            CoxeterDiagramType::A(4),
+            CoxeterDiagramType::D(5),
+            CoxeterDiagramType::H(4),
+            CoxeterDiagramType::AffineD(4),
+            CoxeterDiagramType::E(10),
+        ]
+        .into_iter()
+        .for_each(|coxeter_diagram_type| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            let rank = coxeter_diagram.rank();
+            let mr = coxeter_diagram.matrix_ring();
+            let pr = DensePolyRing::new(coxeter_diagram.cyclotomic_ring(), "lambda");
+            let group = CoxeterGroup::new(coxeter_diagram.clone());
+            let characteristic_polynomial =
+                characteristic_polynomial_of(&mr, &pr, coxeter_diagram.coxeter_element());
+            (0..rank)
+                .permutations(rank)
+                .take(30)
+                .chain([(0..rank).rev().collect()])
+                .for_each(|ordering| {
+                    let coxeter_element = coxeter_diagram
+                        .coxeter_element_for_ordering(&ordering)
+                        .unwrap();
+                    assert!(mr.eq_el(&coxeter_element, group.matrix(&group.element(&ordering)),));
+                    assert!(pr.eq_el(
+                        &characteristic_polynomial,
+                        &characteristic_polynomial_of(&mr, &pr, coxeter_element),
+                    ));
+                });
+            assert!(pr.eq_el(
+                &characteristic_polynomial,
+                &characteristic_polynomial_of(
+                    &mr,
+                    &pr,
+                    coxeter_diagram.bipartite_coxeter_element().unwrap(),
+                ),
+            ));
+        });
+        // On a tree, any two Coxeter elements are conjugate.
+        let coxeter_diagram = CoxeterDiagram::from(CoxeterDiagramType::H(3));
+        let group = CoxeterGroup::new(coxeter_diagram.clone());
+        let elements = group.elements_up_to_length(15);
+        let coxeter_element = group.element(&[0, 1, 2]);
+        [vec![1, 0, 2], vec![2, 1, 0], vec![0, 2, 1]]
+            .into_iter()
+            .for_each(|ordering| {
+                let other = group.element(&ordering);
+                assert!(elements.iter().any(|element| group.eq_el(
+                    &group.mul(
+                        &group.mul(element, &coxeter_element),
+                        &group.inverse(element)
+                    ),
+                    &other,
+                )));
+            });
+        let mr = coxeter_diagram.matrix_ring();
+        assert!(mr.eq_el(
+            &coxeter_diagram
+                .coxeter_element_for_orientation(&[(1, 0), (1, 2)])
+                .unwrap(),
+            &coxeter_diagram
+                .coxeter_element_for_ordering(&[1, 2, 0])
+                .unwrap(),
+        ));
+        assert!(mr.eq_el(
+            &coxeter_diagram.bipartite_coxeter_element().unwrap(),
+            &coxeter_diagram
+                .coxeter_element_for_ordering(&[0, 2, 1])
+                .unwrap(),
+        ));
+        [vec![0, 1], vec![0, 1, 1], vec![0, 1, 3], vec![0, 1, 2, 0]]
+            .into_iter()
+            .for_each(|ordering| {
+                assert!(coxeter_diagram
+                    .coxeter_element_for_ordering(&ordering)
+                    .is_none());
+            });
+        assert!(coxeter_diagram
+            .coxeter_element_for_orientation(&[(0, 3)])
+            .is_none());
+        // The triangle of the affine group of type A2 is an odd cycle.
+        let coxeter_diagram = CoxeterDiagram::from(CoxeterDiagramType::AffineA(2));
+        assert!(coxeter_diagram.bipartite_coxeter_element().is_none());
+        assert!(coxeter_diagram
+            .coxeter_element_for_orientation(&[(0, 1), (1, 2), (2, 0)])
+            .is_none());
+    }
 }
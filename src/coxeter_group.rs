@@ -0,0 +1,1008 @@
+use super::coxeter_diagram::{CoxeterDiagram, CoxeterGroupType};
+use super::cyclotomic::{cyclotomic_sign, lcm, CyclotomicRingBase};
+use super::square_matrix::SquareMatrixRingBase;
+use feanor_math::{
+    matrix::OwnedMatrix,
+    primitive_int::StaticRing,
+    ring::{El, RingBase, RingStore, RingValue},
+};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+pub(crate) type CyclotomicRing = RingValue<CyclotomicRingBase<StaticRing<i64>>>;
+type MatrixRing = RingValue<SquareMatrixRingBase<CyclotomicRing>>;
+pub(crate) type Matrix = <SquareMatrixRingBase<CyclotomicRing> as RingBase>::Element;
+
+// An element is stored through its matrix in the representation used by
+// `CoxeterDiagram::coxeter_element`, the contragredient of the action on the simple roots, together
+// with the matrix of its inverse. Row `i` of the inverse matrix holds the coordinates of `w(a_i)` in
+// the simple roots `a_i`, and row `i` of the matrix those of `w^-1(a_i)`, so both right and left
+// descents can be read off the signs of rows.
+pub struct CoxeterGroupElement {
+    matrix: Matrix,
+    inverse_matrix: Matrix,
+}
+
+pub struct CoxeterGroup {
+    coxeter_diagram: CoxeterDiagram,
+    cyclotomic_ring: CyclotomicRing,
+    matrix_ring: MatrixRing,
+    generators: Vec<Matrix>,
+    // The nonzero entries `(i, g_ik)` of the only column `k` in which the generator `g` differs from
+    // the identity, so that multiplying by a generator takes quadratic time.
+    generator_columns: Vec<Vec<(usize, El<CyclotomicRing>)>>,
+    // The order of the cyclotomic field containing all matrix entries, in which the entries have a
+    // unique reduced form modulo the cyclotomic polynomial.
+    order: usize,
+}
+
+// Iterates over the shortlex normal forms of the elements of a finite group by increasing length.
+// An element `w` is tracked through the point `w(p)` in fundamental weight coordinates, where all
+// coordinates of `p` are 1, and the left descents of `w` are its negative coordinates. Every
+// element is produced from its normal form with the first letter removed, so only the elements of
+// a single length are kept at a time. The orbit of `p` under a finite group stays away from the
+// walls, so floats suffice to decide the signs.
+pub struct Elements {
+    schlafli_matrix: Vec<Vec<f64>>,
+    level: Vec<(Vec<f64>, Vec<usize>)>,
+    index: usize,
+}
+
+impl Iterator for Elements {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.level.len() {
+            let schlafli_matrix = &self.schlafli_matrix;
+            let level = &self.level;
+            // Generating by the first letter first keeps each length sorted lexicographically.
+            self.level = (0..schlafli_matrix.len())
+                .flat_map(|j| {
+                    level.iter().filter_map(move |(point, word)| {
+                        if point[j] < 0.0 {
+                            return None;
+                        }
+                        let point = point
+                            .iter()
+                            .zip(&schlafli_matrix[j])
+                            .map(|(x, a)| x - point[j] * a)
+                            .collect_vec();
+                        point[..j].iter().all(|&x| x > 0.0).then(|| {
+                            let word = std::iter::once(j).chain(word.iter().copied()).collect();
+                            (point, word)
+                        })
+                    })
+                })
+                .collect();
+            self.index = 0;
+        }
+        let (_, word) = self.level.get(self.index)?;
+        self.index += 1;
+        Some(word.clone())
+    }
+}
+
+impl CoxeterGroup {
+    pub fn new(coxeter_diagram: CoxeterDiagram) -> Self {
+        let rank = coxeter_diagram.rank();
+        let cyclotomic_ring = coxeter_diagram.cyclotomic_ring();
+        let matrix_ring = coxeter_diagram.matrix_ring();
+        let schlafli_matrix = coxeter_diagram.schlafli_matrix();
+        let generators = (0..rank)
+            .map(|k| {
+                OwnedMatrix::from_fn(rank, rank, |i, j| {
+                    if j != k {
+                        cyclotomic_ring.get_ring().from_int((i == j) as i32)
+                    } else if i != k {
+                        cyclotomic_ring.negate(cyclotomic_ring.clone_el(schlafli_matrix.at(i, j)))
+                    } else {
+                        cyclotomic_ring.neg_one()
+                    }
+                })
+            })
+            .collect_vec();
+        let order = generators
+            .iter()
+            .flat_map(|generator| {
+                (0..rank).flat_map(move |i| (0..rank).map(move |j| generator.at(i, j).len()))
+            })
+            .fold(1, lcm);
+        let generator_columns = generators
+            .iter()
+            .enumerate()
+            .map(|(k, generator)| {
+                (0..rank)
+                    .filter(|&i| generator.at(i, k).iter().any(|&c| c != 0))
+                    .map(|i| (i, cyclotomic_ring.clone_el(generator.at(i, k))))
+                    .collect_vec()
+            })
+            .collect_vec();
+        Self {
+            coxeter_diagram,
+            cyclotomic_ring,
+            matrix_ring,
+            generators,
+            generator_columns,
+            order,
+        }
+    }
+
+    pub fn coxeter_diagram(&self) -> &CoxeterDiagram {
+        &self.coxeter_diagram
+    }
+
+    pub fn rank(&self) -> usize {
+        self.coxeter_diagram.rank()
+    }
+
+    pub fn identity(&self) -> CoxeterGroupElement {
+        CoxeterGroupElement {
+            matrix: self.matrix_ring.one(),
+            inverse_matrix: self.matrix_ring.one(),
+        }
+    }
+
+    pub fn generator(&self, i: usize) -> CoxeterGroupElement {
+        CoxeterGroupElement {
+            matrix: self.matrix_ring.clone_el(&self.generators[i]),
+            inverse_matrix: self.matrix_ring.clone_el(&self.generators[i]),
+        }
+    }
+
+    pub fn element(&self, word: &[usize]) -> CoxeterGroupElement {
+        word.iter().fold(self.identity(), |element, &i| {
+            self.mul_generator(element, i)
+        })
+    }
+
+    pub fn clone_el(&self, element: &CoxeterGroupElement) -> CoxeterGroupElement {
+        CoxeterGroupElement {
+            matrix: self.matrix_ring.clone_el(&element.matrix),
+            inverse_matrix: self.matrix_ring.clone_el(&element.inverse_matrix),
+        }
+    }
+
+    pub fn mul(&self, lhs: &CoxeterGroupElement, rhs: &CoxeterGroupElement) -> CoxeterGroupElement {
+        CoxeterGroupElement {
+            matrix: self.matrix_ring.mul_ref(&lhs.matrix, &rhs.matrix),
+            inverse_matrix: self
+                .matrix_ring
+                .mul_ref(&rhs.inverse_matrix, &lhs.inverse_matrix),
+        }
+    }
+
+    pub fn mul_generator(&self, element: CoxeterGroupElement, i: usize) -> CoxeterGroupElement {
+        CoxeterGroupElement {
+            matrix: self.mul_generator_matrix(element.matrix, i),
+            inverse_matrix: self.generator_mul_matrix(i, element.inverse_matrix),
+        }
+    }
+
+    pub fn generator_mul(&self, i: usize, element: CoxeterGroupElement) -> CoxeterGroupElement {
+        CoxeterGroupElement {
+            matrix: self.generator_mul_matrix(i, element.matrix),
+            inverse_matrix: self.mul_generator_matrix(element.inverse_matrix, i),
+        }
+    }
+
+    // Only column `k` of the product changes.
+    fn mul_generator_matrix(&self, mut matrix: Matrix, k: usize) -> Matrix {
+        (0..self.rank()).for_each(|row| {
+            *matrix.at_mut(row, k) =
+                self.cyclotomic_ring
+                    .sum(self.generator_columns[k].iter().map(|(i, coefficient)| {
+                        self.cyclotomic_ring
+                            .mul_ref(matrix.at(row, *i), coefficient)
+                    }));
+        });
+        matrix
+    }
+
+    // Every row of the product is the same row of `matrix` plus a multiple of its row `k`.
+    fn generator_mul_matrix(&self, k: usize, mut matrix: Matrix) -> Matrix {
+        self.generator_columns[k]
+            .iter()
+            .filter(|(i, _)| *i != k)
+            .for_each(|(i, coefficient)| {
+                (0..self.rank()).for_each(|column| {
+                    let product = self
+                        .cyclotomic_ring
+                        .mul_ref(coefficient, matrix.at(k, column));
+                    self.cyclotomic_ring
+                        .add_assign(matrix.at_mut(*i, column), product);
+                });
+            });
+        (0..self.rank()).for_each(|column| {
+            self.cyclotomic_ring
+                .negate_inplace(matrix.at_mut(k, column));
+        });
+        matrix
+    }
+
+    pub fn inverse(&self, element: &CoxeterGroupElement) -> CoxeterGroupElement {
+        CoxeterGroupElement {
+            matrix: self.matrix_ring.clone_el(&element.inverse_matrix),
+            inverse_matrix: self.matrix_ring.clone_el(&element.matrix),
+        }
+    }
+
+    pub fn eq_el(&self, lhs: &CoxeterGroupElement, rhs: &CoxeterGroupElement) -> bool {
+        self.matrix_ring.eq_el(&lhs.matrix, &rhs.matrix)
+    }
+
+    pub fn is_identity(&self, element: &CoxeterGroupElement) -> bool {
+        self.matrix_ring.is_one(&element.matrix)
+    }
+
+    pub fn matrix<'a>(&self, element: &'a CoxeterGroupElement) -> &'a Matrix {
+        &element.matrix
+    }
+
+    // A canonical form of the element, so that elements can be collected into hash sets.
+    pub(crate) fn key(&self, element: &CoxeterGroupElement) -> Vec<i64> {
+        (0..self.rank())
+            .cartesian_product(0..self.rank())
+            .flat_map(|(i, j)| self.reduced(element.matrix.at(i, j)))
+            .collect()
+    }
+
+    // The coefficients of an entry reduced modulo the cyclotomic polynomial.
+    pub(crate) fn reduced(&self, value: &El<CyclotomicRing>) -> Vec<i64> {
+        self.cyclotomic_ring
+            .get_ring()
+            .reduced_coefficients(value, self.order)
+    }
+
+    pub(crate) fn cyclotomic_ring(&self) -> &CyclotomicRing {
+        &self.cyclotomic_ring
+    }
+
+    // A root is either nonnegative or nonpositive in the simple root basis, so the sign of the
+    // sum of its coordinates tells which one.
+    fn is_negative_row(&self, matrix: &Matrix, i: usize) -> bool {
+        cyclotomic_sign(
+            &self.cyclotomic_ring,
+            &self
+                .cyclotomic_ring
+                .sum((0..self.rank()).map(|j| self.cyclotomic_ring.clone_el(matrix.at(i, j)))),
+        ) == std::cmp::Ordering::Less
+    }
+
+    pub fn is_right_descent(&self, element: &CoxeterGroupElement, i: usize) -> bool {
+        self.is_negative_row(&element.inverse_matrix, i)
+    }
+
+    pub fn is_left_descent(&self, element: &CoxeterGroupElement, i: usize) -> bool {
+        self.is_negative_row(&element.matrix, i)
+    }
+
+    pub fn right_descents(&self, element: &CoxeterGroupElement) -> Vec<usize> {
+        (0..self.rank())
+            .filter(|&i| self.is_right_descent(element, i))
+            .collect()
+    }
+
+    pub fn left_descents(&self, element: &CoxeterGroupElement) -> Vec<usize> {
+        (0..self.rank())
+            .filter(|&i| self.is_left_descent(element, i))
+            .collect()
+    }
+
+    pub fn reduced_word(&self, element: &CoxeterGroupElement) -> Vec<usize> {
+        let mut element = self.clone_el(element);
+        let mut word = std::iter::from_fn(|| {
+            let i = (0..self.rank()).find(|&i| self.is_right_descent(&element, i))?;
+            element = self.mul_generator(std::mem::replace(&mut element, self.identity()), i);
+            Some(i)
+        })
+        .collect_vec();
+        word.reverse();
+        word
+    }
+
+    pub fn length(&self, element: &CoxeterGroupElement) -> usize {
+        self.reduced_word(element).len()
+    }
+
+    // The lexicographically smallest reduced word: its first letter is the smallest left descent,
+    // followed by the normal form of the remaining element.
+    pub fn normal_form(&self, element: &CoxeterGroupElement) -> Vec<usize> {
+        let mut element = self.clone_el(element);
+        std::iter::from_fn(|| {
+            let i = (0..self.rank()).find(|&i| self.is_left_descent(&element, i))?;
+            element = self.generator_mul(i, std::mem::replace(&mut element, self.identity()));
+            Some(i)
+        })
+        .collect()
+    }
+
+    // Climbs through right ascents until none is left, which only terminates for finite groups.
+    pub fn longest_element(&self) -> Option<CoxeterGroupElement> {
+        (self.coxeter_diagram.coxeter_group_type() == CoxeterGroupType::Elliptic).then(|| {
+            let mut element = self.identity();
+            while let Some(i) = (0..self.rank()).find(|&i| !self.is_right_descent(&element, i)) {
+                element = self.mul_generator(element, i);
+            }
+            element
+        })
+    }
+
+    pub fn elements(&self) -> Option<Elements> {
+        (self.coxeter_diagram.coxeter_group_type() == CoxeterGroupType::Elliptic).then(|| {
+            Elements {
+                schlafli_matrix: self.coxeter_diagram.numeric_schlafli_matrix(),
+                level: Vec::from([(vec![1.0; self.rank()], Vec::new())]),
+                index: 0,
+            }
+        })
+    }
+
+    // The permutation `i -> j` of the nodes with `w0 s_i w0 = s_j`.
+    pub fn longest_element_automorphism(&self) -> Option<Vec<usize>> {
+        let longest_element = self.longest_element()?;
+        Some(
+            (0..self.rank())
+                .map(|i| {
+                    let conjugate = self.mul(
+                        &self.mul_generator(self.clone_el(&longest_element), i),
+                        &longest_element,
+                    );
+                    (0..self.rank())
+                        .find(|&j| {
+                            self.matrix_ring
+                                .eq_el(&conjugate.matrix, &self.generators[j])
+                        })
+                        .unwrap()
+                })
+                .collect(),
+        )
+    }
+
+    // Deletion of a right descent `s` of `w`: if `s` is also a right descent of `u`, then
+    // `u <= w` iff `us <= ws`, and otherwise `u <= w` iff `u <= ws`.
+    pub fn bruhat_le(&self, lhs: &CoxeterGroupElement, rhs: &CoxeterGroupElement) -> bool {
+        let mut lhs = self.clone_el(lhs);
+        let mut rhs = self.clone_el(rhs);
+        while let Some(i) = (0..self.rank()).find(|&i| self.is_right_descent(&rhs, i)) {
+            if self.is_right_descent(&lhs, i) {
+                lhs = self.mul_generator(lhs, i);
+            }
+            rhs = self.mul_generator(rhs, i);
+        }
+        self.is_identity(&lhs)
+    }
+
+    // The elements below `element` are the products of the subwords of a reduced word, which
+    // are finitely many in any Coxeter group. They are sorted by length.
+    pub fn bruhat_lower_interval(&self, element: &CoxeterGroupElement) -> Vec<CoxeterGroupElement> {
+        let mut keys = HashSet::from([self.key(&self.identity())]);
+        let mut elements = Vec::from([self.identity()]);
+        self.reduced_word(element).into_iter().for_each(|i| {
+            let products = elements
+                .iter()
+                .map(|element| self.mul_generator(self.clone_el(element), i))
+                .filter(|product| keys.insert(self.key(product)))
+                .collect_vec();
+            elements.extend(products);
+        });
+        elements.sort_by_cached_key(|element| self.length(element));
+        elements
+    }
+
+    pub fn bruhat_interval(
+        &self,
+        lhs: &CoxeterGroupElement,
+        rhs: &CoxeterGroupElement,
+    ) -> Vec<CoxeterGroupElement> {
+        self.bruhat_lower_interval(rhs)
+            .into_iter()
+            .filter(|element| self.bruhat_le(lhs, element))
+            .collect()
+    }
+
+    // Upper intervals are infinite in infinite groups, so these are cut off at `max_length`.
+    pub fn bruhat_upper_interval(
+        &self,
+        element: &CoxeterGroupElement,
+        max_length: usize,
+    ) -> Vec<CoxeterGroupElement> {
+        self.elements_up_to_length(max_length)
+            .into_iter()
+            .filter(|other| self.bruhat_le(element, other))
+            .collect()
+    }
+
+    // Elements of length `l + 1` are those of length `l` times one of their right ascents.
+    pub fn elements_up_to_length(&self, max_length: usize) -> Vec<CoxeterGroupElement> {
+        let mut elements = Vec::from([self.identity()]);
+        let mut level = 0..1;
+        (0..max_length).for_each(|_| {
+            let mut keys = HashSet::new();
+            let products = elements[level.clone()]
+                .iter()
+                .flat_map(|element| {
+                    (0..self.rank())
+                        .filter(|&i| !self.is_right_descent(element, i))
+                        .map(|i| self.mul_generator(self.clone_el(element), i))
+                })
+                .filter(|product| keys.insert(self.key(product)))
+                .collect_vec();
+            level = elements.len()..elements.len() + products.len();
+            elements.extend(products);
+        });
+        elements
+    }
+
+    pub fn parabolic_subgroup(&self, nodes: &[usize]) -> CoxeterGroup {
+        CoxeterGroup::new(self.coxeter_diagram.subdiagram(nodes))
+    }
+
+    // The factorization `w = w^J w_J` with `w_J` in the parabolic subgroup generated by `nodes` and
+    // `w^J` the minimal representative of the left coset `w W_J`, obtained by stripping right
+    // descents in `nodes`. Lengths add up.
+    pub fn parabolic_decomposition(
+        &self,
+        element: &CoxeterGroupElement,
+        nodes: &[usize],
+    ) -> (CoxeterGroupElement, CoxeterGroupElement) {
+        let mut representative = self.clone_el(element);
+        let mut word = std::iter::from_fn(|| {
+            let i = nodes
+                .iter()
+                .copied()
+                .find(|&i| self.is_right_descent(&representative, i))?;
+            representative =
+                self.mul_generator(std::mem::replace(&mut representative, self.identity()), i);
+            Some(i)
+        })
+        .collect_vec();
+        word.reverse();
+        (representative, self.element(&word))
+    }
+
+    pub fn is_minimal_coset_representative(
+        &self,
+        element: &CoxeterGroupElement,
+        nodes: &[usize],
+    ) -> bool {
+        nodes.iter().all(|&i| !self.is_right_descent(element, i))
+    }
+
+    // The minimal representatives `W^J` of the left cosets `w W_J` up to the given length, or all
+    // of them without a bound, which is only allowed for finite groups and `None` otherwise. `W^J`
+    // is closed under taking suffixes of reduced words, so it is generated by multiplying with
+    // generators on the left.
+    pub fn minimal_coset_representatives(
+        &self,
+        nodes: &[usize],
+        max_length: Option<usize>,
+    ) -> Option<Vec<CoxeterGroupElement>> {
+        let max_length = match max_length {
+            Some(max_length) => max_length,
+            None if self.coxeter_diagram.coxeter_group_type() == CoxeterGroupType::Elliptic => {
+                usize::MAX
+            }
+            None => return None,
+        };
+        let mut representatives = Vec::from([self.identity()]);
+        let mut level = 0..1;
+        let mut length = 0;
+        while length < max_length && !level.is_empty() {
+            let mut keys = HashSet::new();
+            let products = representatives[level.clone()]
+                .iter()
+                .flat_map(|representative| {
+                    (0..self.rank())
+                        .filter(|&i| !self.is_left_descent(representative, i))
+                        .map(|i| self.generator_mul(i, self.clone_el(representative)))
+                })
+                .filter(|product| self.is_minimal_coset_representative(product, nodes))
+                .filter(|product| keys.insert(self.key(product)))
+                .collect_vec();
+            level = representatives.len()..representatives.len() + products.len();
+            representatives.extend(products);
+            length += 1;
+        }
+        Some(representatives)
+    }
+
+    // The minimal representatives of the right cosets `W_J w`, the inverses of those of the left
+    // cosets.
+    pub fn minimal_right_coset_representatives(
+        &self,
+        nodes: &[usize],
+        max_length: Option<usize>,
+    ) -> Option<Vec<CoxeterGroupElement>> {
+        Some(
+            self.minimal_coset_representatives(nodes, max_length)?
+                .iter()
+                .map(|representative| self.inverse(representative))
+                .collect(),
+        )
+    }
+
+    // The minimal representatives of the double cosets `W_I w W_J`, which are the elements without
+    // left descents in `I` and right descents in `J`.
+    pub fn minimal_double_coset_representatives(
+        &self,
+        left_nodes: &[usize],
+        right_nodes: &[usize],
+        max_length: Option<usize>,
+    ) -> Option<Vec<CoxeterGroupElement>> {
+        Some(
+            self.minimal_coset_representatives(right_nodes, max_length)?
+                .into_iter()
+                .filter(|representative| {
+                    left_nodes
+                        .iter()
+                        .all(|&i| !self.is_left_descent(representative, i))
+                })
+                .collect(),
+        )
+    }
+
+    pub fn reduce_word(&self, word: &[usize]) -> Vec<usize> {
+        self.normal_form(&self.element(word))
+    }
+
+    pub fn words_eq(&self, lhs: &[usize], rhs: &[usize]) -> bool {
+        self.is_identity(
+            &self.element(
+                &lhs.iter()
+                    .copied()
+                    .chain(rhs.iter().rev().copied())
+                    .collect_vec(),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CoxeterGroup;
+    use crate::coxeter_diagram::{CoxeterDiagram, CoxeterDiagramType, CoxeterLabel};
+    use feanor_math::ring::RingStore;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CoxeterGroup>();
+    }
+
+    #[test]
+    fn test_relations() {
+        let coxeter_diagram = CoxeterDiagram::from(CoxeterDiagramType::H(4));
+        let group = CoxeterGroup::new(coxeter_diagram.clone());
+        (0..4).for_each(|i| {
+            assert!(group.is_identity(&group.element(&[i, i])));
+        });
+        [
+            (0, 1, 3),
+            (1, 2, 3),
+            (2, 3, 5),
+            (0, 2, 2),
+            (0, 3, 2),
+            (1, 3, 2),
+        ]
+        .into_iter()
+        .for_each(|(i, j, label)| {
+            let word = [i, j].repeat(label);
+            assert!(group.is_identity(&group.element(&word)));
+            assert!(!group.is_identity(&group.element(&word[1..])));
+            assert_eq!(group.length(&group.element(&word[1..])), 1);
+            assert_eq!(group.length(&group.element(&word[..label])), label);
+        });
+        assert!(coxeter_diagram.matrix_ring().eq_el(
+            group.matrix(&group.element(&[0, 1, 2, 3])),
+            &coxeter_diagram.coxeter_element(),
+        ));
+    }
+
+    #[test]
+    fn test_element() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::A(3)));
+        let u = group.element(&[0, 1, 0, 2]);
+        let v = group.element(&[2, 1]);
+        assert!(group.eq_el(&u, &group.element(&[1, 0, 1, 2])));
+        assert!(group.eq_el(&group.mul(&u, &v), &group.element(&[0, 1, 0, 2, 2, 1]),));
+        assert!(group.eq_el(&group.inverse(&u), &group.element(&[2, 0, 1, 0])));
+        assert!(group.is_identity(&group.mul(&u, &group.inverse(&u))));
+        assert_eq!(group.length(&u), 4);
+        assert_eq!(group.length(&group.mul(&u, &v)), 2);
+        assert_eq!(group.reduced_word(&group.mul(&u, &v)), vec![1, 0]);
+        assert_eq!(group.left_descents(&u), vec![0, 1]);
+        assert_eq!(group.right_descents(&u), vec![0, 2]);
+        assert_eq!(
+            group.reduced_word(&group.element(&[0, 1, 0, 1])),
+            vec![1, 0]
+        );
+        // The longest element of A3 has length 6 and every generator as a descent.
+        let longest = group.element(&[0, 1, 0, 2, 1, 0]);
+        assert_eq!(group.length(&longest), 6);
+        assert_eq!(group.left_descents(&longest), vec![0, 1, 2]);
+        assert_eq!(group.right_descents(&longest), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_infinite_element() {
+        // The (2, 3, infinity) triangle group, which is PGL(2, Z).
+        let group = CoxeterGroup::new(
+            CoxeterDiagram::new(
+                3,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((1, 2), CoxeterLabel::Infinite),
+                ],
+            )
+            .unwrap(),
+        );
+        assert!(group.is_identity(&group.element(&[0, 1, 0, 1, 0, 1])));
+        assert!(group.is_identity(&group.element(&[0, 2, 0, 2])));
+        let element = group.element(&[1, 2].repeat(10));
+        assert_eq!(group.length(&element), 20);
+        assert_eq!(group.left_descents(&element), vec![1]);
+        assert_eq!(group.right_descents(&element), vec![2]);
+        assert_eq!(group.length(&group.element(&[0, 1, 0, 1, 2])), 3);
+        assert!(group.is_identity(&group.element(&[1, 2, 0, 2, 0, 1])));
+    }
+
+    #[test]
+    fn test_normal_form() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::H(4)));
+        assert_eq!(group.reduce_word(&[3, 2, 3, 2, 3]), vec![2, 3, 2, 3, 2]);
+        assert!(group.words_eq(&[3, 2, 3, 2, 3], &[2, 3, 2, 3, 2]));
+        assert!(!group.words_eq(&[3, 2, 3, 2], &[2, 3, 2, 3]));
+        assert_eq!(
+            group.reduce_word(&[1, 2, 1, 3, 2, 3, 2, 3, 1, 0, 1]),
+            vec![2, 1, 0, 3, 2, 3, 2, 1, 0],
+        );
+        // The longest element reached by climbing with the smallest and with the largest ascent.
+        let climb = |ascents: &dyn Fn(Vec<usize>) -> Option<usize>| {
+            let mut element = group.identity();
+            let mut word = Vec::new();
+            while let Some(i) = ascents(
+                (0..4)
+                    .filter(|&i| !group.is_right_descent(&element, i))
+                    .collect(),
+            ) {
+                element = group.mul_generator(element, i);
+                word.push(i);
+            }
+            word
+        };
+        let lhs = climb(&|ascents| ascents.first().copied());
+        let rhs = climb(&|ascents| ascents.last().copied());
+        assert_ne!(lhs, rhs);
+        assert_eq!(lhs.len(), 60);
+        assert!(group.words_eq(&lhs, &rhs));
+        assert_eq!(group.reduce_word(&lhs), group.reduce_word(&rhs));
+        assert_eq!(
+            group.reduce_word(&lhs.iter().chain(&rhs).copied().collect::<Vec<_>>()),
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_infinite_normal_form() {
+        // The compact hyperbolic group [5, 3, 5].
+        let group = CoxeterGroup::new(
+            CoxeterDiagram::new(
+                4,
+                [
+                    ((0, 1), CoxeterLabel::Finite(5)),
+                    ((1, 2), CoxeterLabel::Finite(3)),
+                    ((2, 3), CoxeterLabel::Finite(5)),
+                ],
+            )
+            .unwrap(),
+        );
+        assert!(group.words_eq(&[0, 1].repeat(5), &[]));
+        assert!(group.words_eq(&[3, 2, 3, 2, 3, 0], &[0, 2, 3, 2, 3, 2]));
+        assert_eq!(
+            group.reduce_word(&[3, 2, 3, 2, 3, 0, 1, 0, 1, 0]),
+            vec![0, 2, 3, 2, 3, 2, 1, 0, 1, 0],
+        );
+        let word = [0, 1, 2, 3].repeat(6);
+        assert_eq!(group.reduce_word(&word).len(), 24);
+        assert_eq!(
+            group.reduce_word(
+                &word
+                    .iter()
+                    .chain(word.iter().rev())
+                    .copied()
+                    .collect::<Vec<_>>()
+            ),
+            vec![],
+        );
+        // The (2, 3, 7) triangle group.
+        let group = CoxeterGroup::new(
+            CoxeterDiagram::new(
+                3,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((1, 2), CoxeterLabel::Finite(7)),
+                ],
+            )
+            .unwrap(),
+        );
+        assert!(group.words_eq(&[1, 2].repeat(7), &[]));
+        assert_eq!(group.reduce_word(&[2, 1].repeat(4)), [1, 2].repeat(3));
+        assert_eq!(group.reduce_word(&[1, 0, 1, 2, 0, 2]), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_longest_element() {
+        [
+            (CoxeterDiagramType::A(4), vec![3, 2, 1, 0]),
+            (CoxeterDiagramType::B(4), vec![0, 1, 2, 3]),
+            (CoxeterDiagramType::D(4), vec![0, 1, 2, 3]),
+            (CoxeterDiagramType::D(5), vec![0, 1, 2, 4, 3]),
+            (CoxeterDiagramType::E(6), vec![5, 1, 4, 3, 2, 0]),
+            (CoxeterDiagramType::F(4), vec![0, 1, 2, 3]),
+            (CoxeterDiagramType::H(3), vec![0, 1, 2]),
+            (CoxeterDiagramType::I2(5), vec![1, 0]),
+            (CoxeterDiagramType::I2(6), vec![0, 1]),
+        ]
+        .into_iter()
+        .for_each(|(coxeter_diagram_type, automorphism)| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            let group = CoxeterGroup::new(coxeter_diagram.clone());
+            let longest_element = group.longest_element().unwrap();
+            assert_eq!(
+                group.length(&longest_element) as u64,
+                coxeter_diagram
+                    .coxeter_group_info()
+                    .unwrap()
+                    .exponents
+                    .iter()
+                    .sum(),
+            );
+            assert_eq!(
+                group.left_descents(&longest_element),
+                (0..group.rank()).collect::<Vec<_>>(),
+            );
+            assert!(group.is_identity(&group.mul(&longest_element, &longest_element)));
+            assert_eq!(group.longest_element_automorphism().unwrap(), automorphism);
+        });
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::A(2)));
+        assert!(group.eq_el(
+            &group.longest_element().unwrap(),
+            &group.element(&[0, 1, 0])
+        ));
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::AffineA(2)));
+        assert!(group.longest_element().is_none());
+        assert!(group.longest_element_automorphism().is_none());
+    }
+
+    #[test]
+    fn test_bruhat_order() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::A(3)));
+        let elements = group.elements_up_to_length(6);
+        assert_eq!(elements.len(), 24);
+        let longest_element = group.longest_element().unwrap();
+        assert_eq!(group.bruhat_lower_interval(&longest_element).len(), 24);
+        // The number of intervals in the Bruhat order of S4.
+        assert_eq!(
+            elements
+                .iter()
+                .cartesian_product(&elements)
+                .filter(|(lhs, rhs)| group.bruhat_le(lhs, rhs))
+                .count(),
+            213,
+        );
+        elements.iter().for_each(|element| {
+            let lower_interval = group.bruhat_lower_interval(element);
+            assert_eq!(
+                lower_interval.len(),
+                elements
+                    .iter()
+                    .filter(|other| group.bruhat_le(other, element))
+                    .count(),
+            );
+            assert!(lower_interval
+                .iter()
+                .all(|other| group.bruhat_le(other, element)));
+        });
+        assert!(group.bruhat_le(&group.element(&[0]), &group.element(&[1, 0])));
+        assert!(!group.bruhat_le(&group.element(&[0, 1]), &group.element(&[1, 0])));
+        assert!(!group.bruhat_le(&group.element(&[2]), &group.element(&[0, 1, 0])));
+        assert_eq!(
+            group
+                .bruhat_interval(&group.element(&[1]), &group.element(&[1, 0, 2, 1]))
+                .iter()
+                .map(|element| group.length(element))
+                .collect_vec(),
+            vec![1, 2, 2, 2, 2, 3, 3, 3, 3, 4],
+        );
+    }
+
+    #[test]
+    fn test_infinite_bruhat_order() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::AffineA(1)));
+        let element = group.element(&[0, 1].repeat(3));
+        assert_eq!(group.bruhat_lower_interval(&element).len(), 12);
+        assert_eq!(
+            group
+                .bruhat_interval(&group.element(&[1, 0]), &element)
+                .len(),
+            8,
+        );
+        assert_eq!(group.elements_up_to_length(5).len(), 11);
+        assert_eq!(
+            group
+                .bruhat_upper_interval(&group.element(&[1, 0]), 5)
+                .len(),
+            7,
+        );
+        // The (2, 3, 7) triangle group.
+        let group = CoxeterGroup::new(
+            CoxeterDiagram::new(
+                3,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((1, 2), CoxeterLabel::Finite(7)),
+                ],
+            )
+            .unwrap(),
+        );
+        let element = group.element(&[0, 1, 2].repeat(3));
+        assert!(group.bruhat_le(&group.element(&[2, 0, 2, 1]), &element));
+        assert!(!group.bruhat_le(&group.element(&[2, 1, 2, 1, 2, 1]), &element));
+        let lower_interval = group.bruhat_lower_interval(&element);
+        group.elements_up_to_length(9).iter().for_each(|other| {
+            assert_eq!(
+                group.bruhat_le(other, &element),
+                lower_interval.iter().any(|lower| group.eq_el(lower, other)),
+            );
+        });
+    }
+
+    #[test]
+    fn test_elements() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::H(3)));
+        let words = group.elements().unwrap().collect_vec();
+        assert_eq!(words.len(), 120);
+        assert!(words
+            .iter()
+            .tuple_windows()
+            .all(|(lhs, rhs)| (lhs.len(), lhs) < (rhs.len(), rhs)));
+        assert!(words
+            .iter()
+            .all(|word| group.normal_form(&group.element(word)) == *word));
+        [
+            CoxeterDiagramType::B(5),
+            CoxeterDiagramType::D(5),
+            CoxeterDiagramType::F(4),
+            CoxeterDiagramType::H(4),
+            CoxeterDiagramType::E(7),
+        ]
+        .into_iter()
+        .for_each(|coxeter_diagram_type| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            let mut counts = Vec::new();
+            CoxeterGroup::new(coxeter_diagram.clone())
+                .elements()
+                .unwrap()
+                .for_each(|word| {
+                    if counts.len() == word.len() {
+                        counts.push(0);
+                    }
+                    counts[word.len()] += 1;
+                });
+            assert_eq!(counts, coxeter_diagram.growth_series().numerator);
+            assert_eq!(
+                counts.iter().sum::<i64>() as u64,
+                coxeter_diagram.coxeter_group_info().unwrap().order,
+            );
+        });
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::AffineA(2)));
+        assert!(group.elements().is_none());
+    }
+
+    #[test]
+    fn test_parabolic() {
+        [
+            (CoxeterDiagramType::A(3), vec![0, 1], 4),
+            (CoxeterDiagramType::H(3), vec![0, 1], 20),
+            (CoxeterDiagramType::H(3), vec![0, 2], 30),
+            (CoxeterDiagramType::B(4), vec![0, 1, 2], 16),
+            (CoxeterDiagramType::D(4), vec![0, 2, 3], 24),
+        ]
+        .into_iter()
+        .for_each(|(coxeter_diagram_type, nodes, count)| {
+            let group = CoxeterGroup::new(CoxeterDiagram::from(coxeter_diagram_type));
+            let subgroup = group.parabolic_subgroup(&nodes);
+            let subgroup_order = subgroup
+                .coxeter_diagram()
+                .growth_series()
+                .numerator
+                .iter()
+                .sum::<i64>();
+            let representatives = group.minimal_coset_representatives(&nodes, None).unwrap();
+            assert_eq!(representatives.len(), count);
+            assert_eq!(
+                representatives.len() as i64 * subgroup_order,
+                group
+                    .coxeter_diagram()
+                    .growth_series()
+                    .numerator
+                    .iter()
+                    .sum::<i64>(),
+            );
+            assert_eq!(
+                group
+                    .minimal_right_coset_representatives(&nodes, None)
+                    .unwrap()
+                    .len(),
+                count,
+            );
+        });
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::A(3)));
+        // The double cosets of S2 x S2 in S4 are the orbits of S2 x S2 acting on S4.
+        assert_eq!(
+            group
+                .minimal_double_coset_representatives(&[0], &[0], None)
+                .unwrap()
+                .len(),
+            7,
+        );
+        assert_eq!(
+            group
+                .minimal_double_coset_representatives(&[0, 1], &[1, 2], None)
+                .unwrap()
+                .len(),
+            2,
+        );
+        group.elements_up_to_length(6).iter().for_each(|element| {
+            let (representative, parabolic) = group.parabolic_decomposition(element, &[0, 2]);
+            assert!(group.eq_el(&group.mul(&representative, &parabolic), element));
+            assert!(group.is_minimal_coset_representative(&representative, &[0, 2]));
+            assert!(group
+                .normal_form(&parabolic)
+                .iter()
+                .all(|&i| i == 0 || i == 2));
+            assert_eq!(
+                group.length(&representative) + group.length(&parabolic),
+                group.length(element),
+            );
+        });
+    }
+
+    #[test]
+    fn test_infinite_parabolic() {
+        // The (2, 3, infinity) triangle group, whose growth series is `W^J(t) [2]_t [3]_t` for the
+        // finite parabolic subgroup generated by the first two nodes.
+        let coxeter_diagram = CoxeterDiagram::new(
+            3,
+            [
+                ((0, 1), CoxeterLabel::Finite(3)),
+                ((1, 2), CoxeterLabel::Infinite),
+            ],
+        )
+        .unwrap();
+        let group = CoxeterGroup::new(coxeter_diagram.clone());
+        let counts = group
+            .minimal_coset_representatives(&[0, 1], Some(8))
+            .unwrap()
+            .iter()
+            .map(|representative| group.length(representative))
+            .counts();
+        let mut series = (0..=8).map(|length| counts[&length] as i64).collect_vec();
+        [2, 3].into_iter().for_each(|degree| {
+            series = (0..=8)
+                .map(|length: usize| {
+                    (length.saturating_sub(degree - 1)..=length)
+                        .map(|index| series[index])
+                        .sum()
+                })
+                .collect();
+        });
+        assert_eq!(series, coxeter_diagram.growth_series().coefficients(9));
+        assert!(group.minimal_coset_representatives(&[0, 1], None).is_none());
+        let element = group.element(&[2, 1, 0, 2, 1, 0, 1]);
+        let (representative, parabolic) = group.parabolic_decomposition(&element, &[0, 1]);
+        assert_eq!(group.normal_form(&representative), vec![2, 1, 2]);
+        assert_eq!(group.normal_form(&parabolic), vec![1, 0]);
+    }
+}
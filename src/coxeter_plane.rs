@@ -0,0 +1,200 @@
+use super::coxeter_diagram::CoxeterDiagram;
+use super::numeric::{numeric_matrix, weight_orbit};
+use itertools::Itertools;
+
+// The Coxeter plane of an irreducible finite group, on which the bipartite Coxeter element acts as
+// a rotation by `2 pi / h`. Points are given in fundamental weight coordinates `p_i = B(x, a_i)`,
+// and the plane is spanned by two vectors `u`, `v` in simple root coordinates, orthonormal under
+// the Schlafli form `B`, so that `x` projects to `(p . u, p . v)`.
+pub struct CoxeterPlane {
+    schlafli_matrix: Vec<Vec<f64>>,
+    basis: [Vec<f64>; 2],
+}
+
+impl CoxeterPlane {
+    pub fn new(coxeter_diagram: CoxeterDiagram) -> Option<Self> {
+        if coxeter_diagram.connected_components().len() != 1 {
+            return None;
+        }
+        let coxeter_number = coxeter_diagram.coxeter_group_info()?.coxeter_number;
+        if coxeter_number <= 2 {
+            return None;
+        }
+        let rank = coxeter_diagram.rank();
+        let schlafli_matrix = coxeter_diagram.numeric_schlafli_matrix();
+        // The Coxeter element acts on fundamental weight coordinates, so its transpose acts on
+        // simple root coordinates through the inverse, with the same plane for `e^(+-2 pi i / h)`.
+        let coxeter_element = numeric_matrix(
+            &coxeter_diagram.cyclotomic_ring(),
+            &coxeter_diagram.bipartite_coxeter_element()?,
+            rank,
+        );
+        let transpose = (0..rank)
+            .map(|i| (0..rank).map(|j| coxeter_element[j][i]).collect_vec())
+            .collect_vec();
+        let angle = 2.0 * std::f64::consts::PI / coxeter_number as f64;
+        let apply = |vector: &[f64]| {
+            transpose
+                .iter()
+                .map(|row| row.iter().zip(vector).map(|(a, x)| a * x).sum::<f64>())
+                .collect_vec()
+        };
+        // The plane is the kernel of `c^2 - 2 cos(2 pi / h) c + 1`.
+        let squared = (0..rank)
+            .map(|j| {
+                let column = (0..rank).map(|i| (i == j) as u8 as f64).collect_vec();
+                let image = apply(&column);
+                let image_squared = apply(&image);
+                (0..rank)
+                    .map(|i| image_squared[i] - 2.0 * angle.cos() * image[i] + column[i])
+                    .collect_vec()
+            })
+            .collect_vec();
+        let quadratic = (0..rank)
+            .map(|i| (0..rank).map(|j| squared[j][i]).collect_vec())
+            .collect_vec();
+        let first = kernel_vector(quadratic);
+        let image = apply(&first);
+        let second = image
+            .iter()
+            .zip(&first)
+            .map(|(y, x)| (y - angle.cos() * x) / angle.sin())
+            .collect_vec();
+        let norm = first
+            .iter()
+            .enumerate()
+            .flat_map(|(i, x)| {
+                let schlafli_matrix = &schlafli_matrix;
+                first
+                    .iter()
+                    .enumerate()
+                    .map(move |(j, y)| x * schlafli_matrix[i][j] * y)
+            })
+            .sum::<f64>()
+            .sqrt();
+        let normalize = |vector: Vec<f64>| vector.into_iter().map(|x| x / norm).collect_vec();
+        Some(Self {
+            schlafli_matrix,
+            basis: [normalize(first), normalize(second)],
+        })
+    }
+
+    pub fn project(&self, point: &[f64]) -> [f64; 2] {
+        self.basis
+            .each_ref()
+            .map(|vector| vector.iter().zip(point).map(|(u, p)| u * p).sum())
+    }
+
+    // The orbit of a point in fundamental weight coordinates, projected to the plane.
+    pub fn project_orbit(&self, point: &[f64]) -> Vec<[f64; 2]> {
+        weight_orbit(&self.schlafli_matrix, &[point.to_vec()])
+            .iter()
+            .map(|point| self.project(point))
+            .collect()
+    }
+
+    // The projections of all roots, positive and negative.
+    pub fn project_roots(&self) -> Vec<[f64; 2]> {
+        weight_orbit(&self.schlafli_matrix, &self.schlafli_matrix)
+            .iter()
+            .map(|point| self.project(point))
+            .collect()
+    }
+}
+
+// A nonzero vector in the kernel of a singular matrix, by Gaussian elimination with partial
+// pivoting, where pivots below a relative tolerance count as zero.
+fn kernel_vector(mut matrix: Vec<Vec<f64>>) -> Vec<f64> {
+    let size = matrix.len();
+    let scale = matrix
+        .iter()
+        .flatten()
+        .fold(0.0_f64, |scale, x| scale.max(x.abs()));
+    let mut pivots = Vec::new();
+    let mut row = 0;
+    (0..size).for_each(|column| {
+        if row == size {
+            return;
+        }
+        let pivot = (row..size)
+            .max_by(|&i, &j| matrix[i][column].abs().total_cmp(&matrix[j][column].abs()))
+            .unwrap();
+        if matrix[pivot][column].abs() < 1e-9 * scale.max(1.0) {
+            return;
+        }
+        matrix.swap(row, pivot);
+        (0..size).filter(|&i| i != row).for_each(|i| {
+            let factor = matrix[i][column] / matrix[row][column];
+            (0..size).for_each(|k| matrix[i][k] -= factor * matrix[row][k]);
+        });
+        pivots.push(column);
+        row += 1;
+    });
+    let free = (0..size).find(|column| !pivots.contains(column)).unwrap();
+    let mut vector = vec![0.0; size];
+    vector[free] = 1.0;
+    pivots.iter().enumerate().for_each(|(row, &column)| {
+        vector[column] = -matrix[row][free] / matrix[row][column];
+    });
+    vector
+}
+
+#[cfg(test)]
+mod test {
+    use super::CoxeterPlane;
+    use crate::coxeter_diagram::{CoxeterDiagram, CoxeterDiagramType};
+    use itertools::Itertools;
+
+    fn radii(points: &[[f64; 2]]) -> Vec<(i64, usize)> {
+        points
+            .iter()
+            .map(|[x, y]| ((x * x + y * y).sqrt() * 1e6).round() as i64)
+            .sorted()
+            .dedup_with_count()
+            .map(|(count, radius)| (radius, count))
+            .collect()
+    }
+
+    #[test]
+    fn test_petrie_projection() {
+        // The roots of E8 lie on 8 rings of 30 and those of H4 on 4 rings of 30.
+        [
+            (CoxeterDiagramType::E(8), 8, 30),
+            (CoxeterDiagramType::H(4), 4, 30),
+            (CoxeterDiagramType::F(4), 2, 24),
+            (CoxeterDiagramType::H(3), 3, 10),
+        ]
+        .into_iter()
+        .for_each(|(coxeter_diagram_type, ring_count, ring_size)| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            let coxeter_number = coxeter_diagram.coxeter_group_info().unwrap().coxeter_number;
+            let coxeter_plane = CoxeterPlane::new(coxeter_diagram).unwrap();
+            let roots = coxeter_plane.project_roots();
+            let radii = radii(&roots);
+            assert_eq!(radii.len(), ring_count);
+            assert!(radii.iter().all(|&(_, count)| count == ring_size));
+            // The projection is symmetric under the rotation by `2 pi / h`.
+            let angle = 2.0 * std::f64::consts::PI / coxeter_number as f64;
+            assert!(roots.iter().all(|[x, y]| {
+                let rotated = [
+                    x * angle.cos() - y * angle.sin(),
+                    x * angle.sin() + y * angle.cos(),
+                ];
+                roots
+                    .iter()
+                    .any(|root| (root[0] - rotated[0]).hypot(root[1] - rotated[1]) < 1e-6)
+            }));
+        });
+    }
+
+    #[test]
+    fn test_project_orbit() {
+        let coxeter_plane =
+            CoxeterPlane::new(CoxeterDiagram::from(CoxeterDiagramType::A(2))).unwrap();
+        let orbit = coxeter_plane.project_orbit(&[1.0, 0.0]);
+        assert_eq!(orbit.len(), 3);
+        assert_eq!(radii(&orbit).len(), 1);
+        assert!(CoxeterPlane::new(CoxeterDiagram::from(CoxeterDiagramType::AffineA(2))).is_none());
+        assert!(CoxeterPlane::new(CoxeterDiagram::from(CoxeterDiagramType::A(1))).is_none());
+    }
+}
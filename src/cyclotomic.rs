@@ -1,17 +1,100 @@
 use feanor_math::{
-    homomorphism::CanHomFrom,
-    ring::{El, RingBase, RingStore},
+    algorithms::cyclotomic::cyclotomic_polynomial,
+    divisibility::DivisibilityRingStore,
+    field::FieldStore,
+    homomorphism::{CanHomFrom, Homomorphism},
+    integer::{int_cast, BigIntRing, IntegerRing, IntegerRingStore},
+    ordered::OrderedRingStore,
+    primitive_int::StaticRing,
+    ring::{El, RingBase, RingExtensionStore, RingStore},
+    rings::{
+        poly::{dense_poly::DensePolyRing, derive_poly, PolyRingStore},
+        rational::RationalField,
+    },
 };
 use itertools::Itertools;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 #[derive(Clone)]
 pub struct CyclotomicRingBase<R> {
     base_ring: R,
+    // The coefficients of the cyclotomic polynomials by order, used to reduce every product and
+    // shared between clones of the ring.
+    cyclotomic_coefficients: Arc<Mutex<HashMap<usize, Arc<[i64]>>>>,
 }
 
 impl<R> CyclotomicRingBase<R> {
     pub fn new(base_ring: R) -> Self {
-        Self { base_ring }
+        Self {
+            base_ring,
+            cyclotomic_coefficients: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn cyclotomic_coefficients(&self, order: usize) -> Arc<[i64]> {
+        self.cyclotomic_coefficients
+            .lock()
+            .unwrap()
+            .entry(order)
+            .or_insert_with(|| cyclotomic_coefficients(order).into())
+            .clone()
+    }
+}
+
+impl<R> CyclotomicRingBase<R>
+where
+    R: RingStore,
+{
+    // Reduces the coefficients of an element of order `N = els.len()` modulo `Phi_N`, leaving
+    // only those below its degree nonzero, which gives the unique form of the element.
+    fn reduce(&self, els: &mut [El<R>]) {
+        let order = els.len();
+        let cyclotomic = self.cyclotomic_coefficients(order);
+        let degree = cyclotomic.len() - 1;
+        (degree..order).rev().for_each(|index| {
+            if !self.base_ring.is_zero(&els[index]) {
+                let el = std::mem::replace(&mut els[index], self.base_ring.zero());
+                cyclotomic[..degree]
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &coefficient)| coefficient != 0)
+                    .for_each(|(offset, &coefficient)| {
+                        let target = &mut els[index - degree + offset];
+                        match coefficient {
+                            1 => self.base_ring.sub_assign_ref(target, &el),
+                            -1 => self.base_ring.add_assign_ref(target, &el),
+                            _ => self.base_ring.sub_assign(
+                                target,
+                                self.base_ring.mul_ref_snd(
+                                    self.base_ring.get_ring().from_int(
+                                        i32::try_from(coefficient)
+                                            .expect("cyclotomic coefficient out of range"),
+                                    ),
+                                    &el,
+                                ),
+                            ),
+                        }
+                    });
+            }
+        });
+    }
+
+    // The coefficients of `value` on `zeta_N^k` for `k` below the degree of `Phi_N`, which are
+    // unique, where `N` is a multiple of the order of `value`.
+    pub(crate) fn reduced_coefficients(&self, value: &[El<R>], order: usize) -> Vec<El<R>> {
+        let mut els = std::iter::repeat_with(|| self.base_ring.zero())
+            .take(order)
+            .collect_vec();
+        els.iter_mut()
+            .step_by(order / value.len())
+            .zip_eq(value)
+            .for_each(|(el, value_el)| self.base_ring.add_assign_ref(el, value_el));
+        self.reduce(&mut els);
+        els.truncate(self.cyclotomic_coefficients(order).len() - 1);
+        els
     }
 }
 
@@ -50,7 +133,7 @@ where
             .for_each(|(el, lhs_el)| self.base_ring.add_assign(el, lhs_el));
         els.iter_mut()
             .step_by(order / rhs_order)
-            .zip_eq(rhs.into_iter())
+            .zip_eq(rhs)
             .for_each(|(el, rhs_el)| self.base_ring.add_assign(el, rhs_el));
         *lhs = els;
     }
@@ -83,6 +166,10 @@ where
                     self.base_ring.mul_ref(lhs_el, rhs_el),
                 );
             });
+        // Reducing modulo the cyclotomic polynomial keeps the coefficients bounded by the size
+        // of the element and its conjugates; otherwise they may grow exponentially in the number
+        // of multiplications.
+        self.reduce(&mut els);
         *lhs = els;
     }
 
@@ -101,7 +188,7 @@ where
                             .map(|()| p)
                     })
                 })
-                .filter_map(|option_p| option_p)
+                .flatten()
                 .map(|p| {
                     let mut els = std::iter::repeat_with(|| self.base_ring.one())
                         .take(p as usize)
@@ -167,15 +254,63 @@ where
     }
 }
 
-fn lcm(a: usize, b: usize) -> usize {
-    debug_assert!(a != 0);
-    debug_assert!(b != 0);
+// Coefficients of the cyclotomic polynomial of the given order, computed as the product of
+// `(x^d - 1)^mu(order / d)` over the divisors `d` of `order`.
+pub(crate) fn cyclotomic_coefficients(order: usize) -> Vec<i64> {
+    let mobius = |n: usize| {
+        (2..=n)
+            .try_fold((n, 1), |(n, mobius), p| match n % (p * p) {
+                0 => None,
+                _ if n % p == 0 => Some((n / p, -mobius)),
+                _ => Some((n, mobius)),
+            })
+            .map_or(0, |(_, mobius)| mobius)
+    };
+    let (numerator, denominator) = (1..=order).filter(|d| order.is_multiple_of(*d)).fold(
+        (vec![1], vec![1]),
+        |(numerator, denominator), d| {
+            let mul = |polynomial: Vec<i64>| {
+                let mut product = vec![0; polynomial.len() + d];
+                polynomial.iter().enumerate().for_each(|(index, &c)| {
+                    product[index + d] += c;
+                    product[index] -= c;
+                });
+                product
+            };
+            match mobius(order / d) {
+                1 => (mul(numerator), denominator),
+                -1 => (numerator, mul(denominator)),
+                _ => (numerator, denominator),
+            }
+        },
+    );
+    let degree = denominator.len() - 1;
+    let mut remainder = numerator;
+    let mut quotient = vec![0; remainder.len() - degree];
+    (0..quotient.len()).rev().for_each(|index| {
+        let c = remainder[index + degree];
+        quotient[index] = c;
+        denominator.iter().enumerate().for_each(|(offset, &d)| {
+            remainder[index + offset] -= c * d;
+        });
+    });
+    debug_assert!(remainder.iter().all(|&c| c == 0));
+    quotient
+}
+
+pub(crate) fn gcd(a: usize, b: usize) -> usize {
     let mut a0 = a;
     let mut b0 = b;
     while b0 != 0 {
         (a0, b0) = (b0, a0 % b0);
     }
-    a / a0 * b
+    a0
+}
+
+pub(crate) fn lcm(a: usize, b: usize) -> usize {
+    debug_assert!(a != 0);
+    debug_assert!(b != 0);
+    a / gcd(a, b) * b
 }
 
 pub fn cyclotomic_root_of_unity<R>(
@@ -227,9 +362,164 @@ where
     }))
 }
 
+pub fn cyclotomic_sign<R>(
+    ring: &impl RingStore<Type = CyclotomicRingBase<R>>,
+    value: &<CyclotomicRingBase<R> as RingBase>::Element,
+) -> std::cmp::Ordering
+where
+    R: RingStore,
+    R::Type: IntegerRing,
+{
+    let base_ring = &ring.get_ring().base_ring;
+    let order = value.len();
+    debug_assert!(ring.is_zero(
+        &ring.sub_ref(
+            value,
+            &(0..order)
+                .map(|index| base_ring.clone_el(&value[(order - index) % order]))
+                .collect_vec()
+        )
+    ));
+    if ring.is_zero(value) {
+        return std::cmp::Ordering::Equal;
+    }
+    // Fast path: the floating-point sum is trusted whenever it is far away from zero compared
+    // with a generous bound on its rounding error.
+    let numeric = value
+        .iter()
+        .enumerate()
+        .map(|(index, el)| {
+            base_ring.to_float_approx(el)
+                * (2.0 * std::f64::consts::PI * index as f64 / order as f64).cos()
+        })
+        .sum::<f64>();
+    let error_bound = value
+        .iter()
+        .map(|el| base_ring.to_float_approx(el).abs())
+        .sum::<f64>()
+        * (order + 4) as f64
+        * 64.0
+        * f64::EPSILON;
+    if numeric.abs() > error_bound {
+        return numeric.partial_cmp(&0.0).unwrap();
+    }
+    cyclotomic_exact_sign(ring, value)
+}
+
+// Writes the real element as `G(c) / 2` with `c = 2 cos(2 pi / order)` and `G` an integral
+// polynomial, and determines the sign of `G` at `c` via a Tarski query on the minimal
+// polynomial of `c`, with an isolating interval for `c` as its largest root.
+fn cyclotomic_exact_sign<R>(
+    ring: &impl RingStore<Type = CyclotomicRingBase<R>>,
+    value: &<CyclotomicRingBase<R> as RingBase>::Element,
+) -> std::cmp::Ordering
+where
+    R: RingStore,
+    R::Type: IntegerRing,
+{
+    const ZZ: BigIntRing = BigIntRing::RING;
+    let base_ring = &ring.get_ring().base_ring;
+    let order = value.len();
+    let pr = DensePolyRing::new(RationalField::new(ZZ), "T");
+    let qq = pr.base_ring();
+    let to_rational = |el: &El<R>| {
+        qq.inclusion()
+            .map(int_cast(base_ring.clone_el(el), ZZ, base_ring))
+    };
+    let chebyshev = std::iter::successors(
+        Some((pr.int_hom().map(2), pr.indeterminate())),
+        |(previous, current)| {
+            Some((
+                pr.clone_el(current),
+                pr.sub_ref_snd(pr.mul_ref(&pr.indeterminate(), current), previous),
+            ))
+        },
+    )
+    .map(|(current, _)| current)
+    .take(order.max(2))
+    .collect_vec();
+    let numerator = pr.sum(
+        value
+            .iter()
+            .zip(chebyshev.iter())
+            .map(|(el, polynomial)| pr.inclusion().mul_ref_map(polynomial, &to_rational(el))),
+    );
+    if order <= 2 {
+        let point = if order == 1 { 2 } else { -2 };
+        return qq.cmp(
+            &pr.evaluate(&numerator, &qq.int_hom().map(point), qq.identity()),
+            &qq.zero(),
+        );
+    }
+    let cyclotomic = cyclotomic_polynomial(&pr, order);
+    let half_degree = pr.degree(&cyclotomic).unwrap() / 2;
+    let minimal_polynomial = pr.sum((0..=half_degree).map(|index| {
+        let coefficient = pr.coefficient_at(&cyclotomic, half_degree + index);
+        if index == 0 {
+            pr.inclusion().map_ref(coefficient)
+        } else {
+            pr.inclusion().mul_ref_map(&chebyshev[index], coefficient)
+        }
+    }));
+    let monic = |polynomial: &El<DensePolyRing<RationalField<BigIntRing>>>| {
+        pr.inclusion()
+            .mul_ref_map(polynomial, &qq.invert(pr.lc(polynomial).unwrap()).unwrap())
+    };
+    let remainder_sequence = std::iter::successors(
+        Some((
+            pr.clone_el(&minimal_polynomial),
+            pr.div_rem_monic(
+                pr.mul(derive_poly(&pr, &minimal_polynomial), numerator),
+                &monic(&minimal_polynomial),
+            )
+            .1,
+        )),
+        |(previous, current)| {
+            (!pr.is_zero(current)).then(|| {
+                (
+                    pr.clone_el(current),
+                    pr.negate(pr.div_rem_monic(pr.clone_el(previous), &monic(current)).1),
+                )
+            })
+        },
+    )
+    .map(|(current, _)| current)
+    .collect_vec();
+    let sign_changes = |point: &El<RationalField<BigIntRing>>| {
+        remainder_sequence
+            .iter()
+            .map(|polynomial| qq.cmp(&pr.evaluate(polynomial, point, qq.identity()), &qq.zero()))
+            .filter(|&ordering| ordering != std::cmp::Ordering::Equal)
+            .tuple_windows()
+            .filter(|(lhs, rhs)| lhs != rhs)
+            .count()
+    };
+    let numeric_root =
+        |exponent: usize| 2.0 * (2.0 * std::f64::consts::PI * exponent as f64 / order as f64).cos();
+    let largest_root = numeric_root(1);
+    let lower_bound = (2..order / 2)
+        .find(|&exponent| gcd(exponent, order) == 1)
+        .map_or(largest_root - 1.0, |exponent| {
+            (largest_root + numeric_root(exponent)) / 2.0
+        });
+    let lower_bound = qq.div(
+        &qq.inclusion().map(int_cast(
+            (lower_bound * (1_u64 << 40) as f64).round() as i64,
+            ZZ,
+            StaticRing::<i64>::RING,
+        )),
+        &qq.inclusion().map(ZZ.power_of_two(40)),
+    );
+    let upper_bound = qq.int_hom().map(3);
+    let tarski_query = sign_changes(&lower_bound) as i64 - sign_changes(&upper_bound) as i64;
+    tarski_query.cmp(&0)
+}
+
 #[cfg(test)]
 mod test {
-    use super::{cyclotomic_root_of_unity, CyclotomicRingBase};
+    use super::{
+        cyclotomic_exact_sign, cyclotomic_root_of_unity, cyclotomic_sign, CyclotomicRingBase,
+    };
     use feanor_math::ring::{RingStore, RingValue};
 
     #[test]
@@ -265,4 +555,78 @@ mod test {
             cr.negate(cyclotomic_root_of_unity(&cr, 3, 2))
         ])));
     }
+
+    #[test]
+    fn test_reduced_coefficients() {
+        let cr = RingValue::from(CyclotomicRingBase::new(
+            feanor_math::primitive_int::StaticRing::<i64>::default(),
+        ));
+        // `2^60 (1 + zeta_3 + zeta_3^2) + 5`, whose value is not exact in floating point.
+        let value = cr.add(
+            vec![(1 << 60) + 5, 1 << 60, 1 << 60],
+            cyclotomic_root_of_unity(&cr, 6, 3),
+        );
+        assert_eq!(cr.get_ring().reduced_coefficients(&value, 6), vec![4, 0]);
+        assert_eq!(
+            cr.get_ring()
+                .reduced_coefficients(&cyclotomic_root_of_unity(&cr, 4, 3), 4),
+            vec![0, -1]
+        );
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CyclotomicRingBase<feanor_math::primitive_int::StaticRing<i64>>>();
+    }
+
+    #[test]
+    fn test_cyclotomic_sign() {
+        let cr = RingValue::from(CyclotomicRingBase::new(
+            feanor_math::primitive_int::StaticRing::<i64>::default(),
+        ));
+        let cosine = |order: u64, exponent: u64| {
+            cr.add(
+                cyclotomic_root_of_unity(&cr, order, exponent),
+                cyclotomic_root_of_unity(&cr, order, order - exponent),
+            )
+        };
+        // 2 cos(pi / 5) - 1 = 2 cos(2 pi / 5) is the positive root of x^2 + x - 1.
+        let golden = cosine(10, 1);
+        let values = [
+            (
+                cr.sub_ref_fst(&golden, cr.one()),
+                std::cmp::Ordering::Greater,
+            ),
+            (
+                cr.sub(cr.sub_ref_fst(&golden, cr.one()), cosine(5, 1)),
+                std::cmp::Ordering::Equal,
+            ),
+            (cr.sub(cosine(5, 2), cr.neg_one()), std::cmp::Ordering::Less),
+            (
+                cr.sub(
+                    cr.mul_ref(&golden, &golden),
+                    cr.add_ref_fst(&golden, cr.one()),
+                ),
+                std::cmp::Ordering::Equal,
+            ),
+            (
+                cr.sub(cosine(14, 1), cosine(7, 1)),
+                std::cmp::Ordering::Greater,
+            ),
+            (
+                cr.sub(cosine(24, 5), cr.sub(cosine(8, 1), cosine(12, 1))),
+                std::cmp::Ordering::Greater,
+            ),
+            (cr.sub(cosine(2, 1), cr.one()), std::cmp::Ordering::Less),
+            (cosine(9, 4), std::cmp::Ordering::Less),
+            (cosine(60, 7), std::cmp::Ordering::Greater),
+        ];
+        values.into_iter().for_each(|(value, ordering)| {
+            assert_eq!(cyclotomic_sign(&cr, &value), ordering);
+            if ordering != std::cmp::Ordering::Equal {
+                assert_eq!(cyclotomic_exact_sign(&cr, &value), ordering);
+            }
+        });
+    }
 }
@@ -71,7 +71,7 @@ impl DynkinDiagram {
                         Bond::Triple => -3,
                     })
                 })
-                .unwrap_or_else(|| if i == j { 2 } else { 0 })
+                .unwrap_or(if i == j { 2 } else { 0 })
         })
     }
 }
@@ -0,0 +1,631 @@
+use super::coxeter_group::{CoxeterGroup, CyclotomicRing};
+use super::cyclotomic::{lcm, CyclotomicRingBase};
+use super::root_system::root_key;
+use feanor_math::{
+    divisibility::DivisibilityRingStore,
+    homomorphism::Homomorphism,
+    integer::{int_cast, BigIntRing},
+    pid::EuclideanRingStore,
+    primitive_int::StaticRing,
+    ring::{El, RingBase, RingStore, RingValue},
+};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+const ZZ: BigIntRing = BigIntRing::RING;
+
+// The coefficients of invariants grow too quickly with the degrees to fit into `i64`, so they are
+// computed over the integers of arbitrary size.
+pub type BigCyclotomicRing = RingValue<CyclotomicRingBase<BigIntRing>>;
+
+// A polynomial function on the reflection representation in the coordinates `x_i` of
+// `v = sum_i x_i a_i`, given by its terms sorted by exponents.
+pub struct Polynomial {
+    terms: Vec<(Vec<usize>, El<BigCyclotomicRing>)>,
+}
+
+impl Polynomial {
+    pub fn terms(&self) -> &[(Vec<usize>, El<BigCyclotomicRing>)] {
+        &self.terms
+    }
+
+    pub fn degree(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|(exponents, _)| exponents.iter().sum())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn derivative(&self, i: usize) -> Self {
+        Self {
+            terms: self
+                .terms
+                .iter()
+                .filter(|(exponents, _)| exponents[i] > 0)
+                .map(|(exponents, coefficient)| {
+                    let factor = ZZ.int_hom().map(exponents[i] as i32);
+                    let mut exponents = exponents.clone();
+                    exponents[i] -= 1;
+                    (
+                        exponents,
+                        coefficient.iter().map(|c| ZZ.mul_ref(c, &factor)).collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+// Basic invariants of a finite group, one for each degree, of the form
+// `sum_{l in W x_i} l(v)^d` for the orbit of a coordinate function `x_i`, which is fixed by the
+// generators other than `s_i`. The candidates are taken from the smallest orbits first, and one is
+// accepted when it is independent of the products of the invariants accepted before.
+//
+// Returns `None` for infinite groups, and also when at some degree no orbit gives an independent
+// power sum, as the method is not known to succeed for every finite type. It succeeds for the
+// tested types A3, B3, D4, F4, H3, H4, E6, E7 and I2(5); E8 is untested, its power sums of degree
+// up to 30 being too large to expand in a test.
+pub fn basic_invariants(group: &CoxeterGroup) -> Option<Vec<Polynomial>> {
+    let degrees = group.coxeter_diagram().coxeter_group_info()?.degrees;
+    let rank = group.rank();
+    let cr = RingValue::from(CyclotomicRingBase::new(ZZ));
+    let schlafli_matrix = group.coxeter_diagram().schlafli_matrix();
+    let modular = Modular::new(
+        (0..rank)
+            .cartesian_product(0..rank)
+            .map(|(i, j)| schlafli_matrix.at(i, j).len())
+            .fold(1, lcm),
+    );
+    let orbits = (0..rank)
+        .map(|i| Orbit::new(group, &modular, i))
+        .sorted_by_key(|orbit| orbit.size())
+        .collect_vec();
+    // Pseudorandom points from SplitMix64. The consecutive outputs of a congruential generator
+    // would put all points on one line, on which independent invariants become dependent.
+    let mut state = 0_u64;
+    let mut point = || {
+        (0..rank)
+            .map(|_| {
+                state = state.wrapping_add(0x9e3779b97f4a7c15);
+                let z = (state ^ (state >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+                let z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+                (z ^ (z >> 31)) % modular.prime
+            })
+            .collect_vec()
+    };
+    let mut accepted: Vec<(usize, &Orbit)> = Vec::new();
+    degrees
+        .iter()
+        .map(|&degree| {
+            let degree = degree as usize;
+            let products = partitions(
+                &accepted.iter().map(|&(degree, _)| degree).collect_vec(),
+                degree,
+            );
+            let points = (0..=products.len()).map(|_| point()).collect_vec();
+            let values = accepted
+                .iter()
+                .map(|(degree, orbit)| {
+                    points
+                        .iter()
+                        .map(|point| orbit.value(&modular, point, *degree))
+                        .collect_vec()
+                })
+                .collect_vec();
+            let mut span = Vec::new();
+            products.iter().for_each(|exponents| {
+                let product = (0..points.len())
+                    .map(|t| {
+                        exponents
+                            .iter()
+                            .zip(&values)
+                            .fold(1, |product, (&exponent, values)| {
+                                product * modular.pow(values[t], exponent as u64) % modular.prime
+                            })
+                    })
+                    .collect();
+                modular.insert(&mut span, product);
+            });
+            let orbit = orbits.iter().find(|orbit| {
+                let candidate = points
+                    .iter()
+                    .map(|point| orbit.value(&modular, point, degree))
+                    .collect();
+                modular.insert(&mut span, candidate)
+            })?;
+            accepted.push((degree, orbit));
+            Some(orbit.power_sum(&cr, degree))
+        })
+        .collect()
+}
+
+// The orbit of the coordinate function `x_i` under `l -> l s_j`, where
+// `(l s_j)_k = l_k - B(a_k, a_j) l_j`.
+fn linear_form_orbit(group: &CoxeterGroup, i: usize) -> Vec<Vec<El<CyclotomicRing>>> {
+    let rank = group.rank();
+    let cr = group.cyclotomic_ring();
+    let schlafli_matrix = group.coxeter_diagram().schlafli_matrix();
+    let mut orbit = vec![(0..rank)
+        .map(|k| cr.get_ring().from_int((k == i) as i32))
+        .collect_vec()];
+    let mut keys = HashSet::from([root_key(group, &orbit[0])]);
+    let mut index = 0;
+    while index < orbit.len() {
+        (0..rank).for_each(|j| {
+            let form = &orbit[index];
+            let image = (0..rank)
+                .map(|k| cr.sub_ref_fst(&form[k], cr.mul_ref(schlafli_matrix.at(k, j), &form[j])))
+                .collect_vec();
+            if keys.insert(root_key(group, &image)) {
+                orbit.push(image);
+            }
+        });
+        index += 1;
+    }
+    orbit
+}
+
+// The exponents of the products of the given degrees with total degree `degree`.
+fn partitions(degrees: &[usize], degree: usize) -> Vec<Vec<usize>> {
+    let Some((&last, rest)) = degrees.split_last() else {
+        return if degree == 0 {
+            vec![Vec::new()]
+        } else {
+            Vec::new()
+        };
+    };
+    (0..=degree / last)
+        .flat_map(|exponent| {
+            partitions(rest, degree - exponent * last)
+                .into_iter()
+                .map(move |mut exponents| {
+                    exponents.push(exponent);
+                    exponents
+                })
+        })
+        .collect()
+}
+
+// An orbit of linear forms with their residues modulo `p`. When it is closed under `l -> -l`, only
+// one form of each pair is kept, which contributes twice to the power sums of even degree and not at
+// all to those of odd degree.
+struct Orbit {
+    forms: Vec<Vec<El<BigCyclotomicRing>>>,
+    residues: Vec<Vec<u64>>,
+    symmetric: bool,
+}
+
+impl Orbit {
+    fn new(group: &CoxeterGroup, modular: &Modular, i: usize) -> Self {
+        let cr = group.cyclotomic_ring();
+        let orbit = linear_form_orbit(group, i);
+        let negative = |form: &Vec<El<CyclotomicRing>>| {
+            root_key(
+                group,
+                &form.iter().map(|c| cr.negate(cr.clone_el(c))).collect_vec(),
+            )
+        };
+        let keys: HashSet<_> = orbit.iter().map(|form| root_key(group, form)).collect();
+        let symmetric = orbit.iter().all(|form| keys.contains(&negative(form)));
+        let mut kept = HashSet::new();
+        let forms = orbit
+            .into_iter()
+            .filter(|form| {
+                (!symmetric || !kept.contains(&negative(form)))
+                    && kept.insert(root_key(group, form))
+            })
+            .map(|form| {
+                form.into_iter()
+                    .map(|coefficient| {
+                        coefficient
+                            .into_iter()
+                            .map(|c| int_cast(c, ZZ, StaticRing::<i64>::RING))
+                            .collect_vec()
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+        let residues = forms
+            .iter()
+            .map(|form| {
+                form.iter()
+                    .map(|coefficient| modular.residue(coefficient))
+                    .collect()
+            })
+            .collect();
+        Self {
+            forms,
+            residues,
+            symmetric,
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.forms.len() * if self.symmetric { 2 } else { 1 }
+    }
+
+    fn multiplicity(&self, degree: usize) -> i32 {
+        match (self.symmetric, degree % 2) {
+            (false, _) => 1,
+            (true, 0) => 2,
+            (true, _) => 0,
+        }
+    }
+
+    // The value of the power sum modulo `p` at a point given by residues.
+    fn value(&self, modular: &Modular, point: &[u64], degree: usize) -> u64 {
+        let prime = modular.prime;
+        self.residues.iter().fold(0, |value, form| {
+            let form = form
+                .iter()
+                .zip(point)
+                .fold(0, |form, (a, x)| (form + a * x) % prime);
+            (value + modular.pow(form, degree as u64)) % prime
+        }) * self.multiplicity(degree) as u64
+            % prime
+    }
+
+    fn power_sum(&self, cr: &BigCyclotomicRing, degree: usize) -> Polynomial {
+        // The powers `l_k^e` for `e <= degree` of the coefficients of each form.
+        let powers = self
+            .forms
+            .iter()
+            .map(|form| {
+                form.iter()
+                    .map(|coefficient| {
+                        std::iter::successors(Some(cr.one()), |power| {
+                            Some(cr.mul_ref(power, coefficient))
+                        })
+                        .take(degree + 1)
+                        .collect_vec()
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+        let mut terms = Vec::new();
+        orbit_sums(
+            cr,
+            &powers,
+            self.forms.iter().map(|_| cr.one()).collect(),
+            &mut Vec::new(),
+            degree,
+            &mut terms,
+        );
+        let terms = terms
+            .into_iter()
+            .filter(|(_, coefficient)| !cr.is_zero(coefficient))
+            .map(|(exponents, coefficient)| {
+                let multinomial = ZZ.prod(exponents.iter().scan(0, |total, &exponent| {
+                    *total += exponent;
+                    Some(binomial(*total, exponent))
+                }));
+                let multinomial = ZZ.int_hom().mul_map(multinomial, self.multiplicity(degree));
+                (exponents, cr.mul(coefficient, vec![multinomial]))
+            })
+            .collect();
+        Polynomial { terms }
+    }
+}
+
+// The sums `sum_l prod_k l_k^(e_k)` over the forms for all exponents `e` of the given degree
+// extending `prefix`, sharing the products of the powers for the common prefixes.
+fn orbit_sums(
+    cr: &BigCyclotomicRing,
+    powers: &[Vec<Vec<El<BigCyclotomicRing>>>],
+    products: Vec<El<BigCyclotomicRing>>,
+    prefix: &mut Vec<usize>,
+    degree: usize,
+    terms: &mut Vec<(Vec<usize>, El<BigCyclotomicRing>)>,
+) {
+    let k = prefix.len();
+    let multiply = |exponent: usize| {
+        products
+            .iter()
+            .zip(powers)
+            .map(|(product, powers)| match exponent {
+                0 => cr.clone_el(product),
+                _ => cr.mul_ref(product, &powers[k][exponent]),
+            })
+            .collect_vec()
+    };
+    if k + 1 == powers[0].len() {
+        prefix.push(degree);
+        terms.push((prefix.clone(), cr.sum(multiply(degree))));
+        prefix.pop();
+        return;
+    }
+    (0..=degree).for_each(|exponent| {
+        prefix.push(exponent);
+        orbit_sums(
+            cr,
+            powers,
+            multiply(exponent),
+            prefix,
+            degree - exponent,
+            terms,
+        );
+        prefix.pop();
+    });
+}
+
+fn binomial(n: usize, k: usize) -> El<BigIntRing> {
+    (0..k).fold(ZZ.one(), |binomial, i| {
+        ZZ.checked_div(
+            &ZZ.mul(binomial, ZZ.int_hom().map((n - i) as i32)),
+            &ZZ.int_hom().map((i + 1) as i32),
+        )
+        .unwrap()
+    })
+}
+
+// Linear independence is tested on the values at points modulo a prime `p = 1 mod N` with
+// `zeta_N` sent to a primitive `N`-th root of unity modulo `p`. A dependence over the cyclotomic
+// field persists modulo `p` and at every point, so polynomials found independent here are
+// independent. With one point more than there are products, the converse fails only for points
+// on a hypersurface, which pseudorandom points avoid with overwhelming probability.
+struct Modular {
+    prime: u64,
+    order: usize,
+    root: u64,
+}
+
+impl Modular {
+    // The largest prime `p = 1 mod N` below `2^31`, so that products of residues fit into `u64`.
+    fn new(order: usize) -> Self {
+        let prime = (1..(1 << 31) / order as u64)
+            .rev()
+            .map(|k| k * order as u64 + 1)
+            .find(|&p| (2..).take_while(|d| d * d <= p).all(|d| p % d != 0))
+            .unwrap();
+        let prime_factors = (2..=order as u64)
+            .filter(|&q| (order as u64).is_multiple_of(q) && (2..q).all(|d| !q.is_multiple_of(d)))
+            .collect_vec();
+        let mut modular = Self {
+            prime,
+            order,
+            root: 1,
+        };
+        modular.root = (2..)
+            .map(|base| modular.pow(base, (prime - 1) / order as u64))
+            .find(|&root| {
+                prime_factors
+                    .iter()
+                    .all(|&q| modular.pow(root, order as u64 / q) != 1)
+            })
+            .unwrap();
+        modular
+    }
+
+    fn pow(&self, base: u64, exponent: u64) -> u64 {
+        (0..64).rev().fold(1, |power, bit| {
+            let power = power * power % self.prime;
+            if exponent >> bit & 1 == 1 {
+                power * base % self.prime
+            } else {
+                power
+            }
+        })
+    }
+
+    // The image of a cyclotomic integer modulo `p`.
+    fn residue(&self, value: &El<BigCyclotomicRing>) -> u64 {
+        let step = self.pow(self.root, (self.order / value.len()) as u64);
+        let prime = int_cast(self.prime as i64, ZZ, StaticRing::<i64>::RING);
+        value
+            .iter()
+            .fold((0, 1), |(residue, power), c| {
+                let c = int_cast(
+                    ZZ.euclidean_rem(ZZ.clone_el(c), &prime),
+                    StaticRing::<i64>::RING,
+                    ZZ,
+                )
+                .rem_euclid(self.prime as i64) as u64;
+                (
+                    (residue + c * power) % self.prime,
+                    power * step % self.prime,
+                )
+            })
+            .0
+    }
+
+    // Reduces the vector against the echelon rows `(pivot, row)` and adds it if something
+    // remains.
+    fn insert(&self, rows: &mut Vec<(usize, Vec<u64>)>, mut vector: Vec<u64>) -> bool {
+        rows.iter().for_each(|(pivot, row)| {
+            let value = vector[*pivot];
+            if value != 0 {
+                vector.iter_mut().zip(row).for_each(|(entry, &row_value)| {
+                    *entry = (*entry + (self.prime - value) * row_value) % self.prime;
+                });
+            }
+        });
+        let Some(pivot) = vector.iter().position(|&value| value != 0) else {
+            return false;
+        };
+        let inverse = self.pow(vector[pivot], self.prime - 2);
+        vector
+            .iter_mut()
+            .for_each(|entry| *entry = *entry * inverse % self.prime);
+        rows.push((pivot, vector));
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{basic_invariants, Modular, Polynomial, ZZ};
+    use crate::coxeter_diagram::{CoxeterDiagram, CoxeterDiagramType, CoxeterLabel};
+    use crate::coxeter_group::CoxeterGroup;
+    use crate::root_system::RootSystem;
+    use feanor_math::{integer::int_cast, primitive_int::StaticRing};
+    use itertools::Itertools;
+
+    // The residues of the coefficients modulo `p`.
+    fn reduce(modular: &Modular, polynomial: &Polynomial) -> Vec<(Vec<usize>, u64)> {
+        polynomial
+            .terms()
+            .iter()
+            .map(|(exponents, coefficient)| (exponents.clone(), modular.residue(coefficient)))
+            .collect()
+    }
+
+    fn evaluate(modular: &Modular, reduction: &[(Vec<usize>, u64)], point: &[u64]) -> u64 {
+        reduction.iter().fold(0, |value, (exponents, coefficient)| {
+            let term = point
+                .iter()
+                .zip(exponents)
+                .fold(*coefficient, |term, (&x, &exponent)| {
+                    term * modular.pow(x, exponent as u64) % modular.prime
+                });
+            (value + term) % modular.prime
+        })
+    }
+
+    fn determinant(modular: &Modular, mut matrix: Vec<Vec<u64>>) -> u64 {
+        let prime = modular.prime;
+        let size = matrix.len();
+        (0..size).fold(1, |determinant, i| {
+            let Some(pivot) = (i..size).find(|&j| matrix[j][i] != 0) else {
+                return 0;
+            };
+            matrix.swap(i, pivot);
+            let inverse = modular.pow(matrix[i][i], prime - 2);
+            (i + 1..size).for_each(|j| {
+                let factor = matrix[j][i] * inverse % prime;
+                (i..size).for_each(|k| {
+                    matrix[j][k] = (matrix[j][k] + (prime - factor) * matrix[i][k]) % prime;
+                });
+            });
+            let determinant = determinant * matrix[i][i] % prime;
+            if pivot == i {
+                determinant
+            } else {
+                (prime - determinant) % prime
+            }
+        })
+    }
+
+    #[test]
+    fn test_basic_invariants() {
+        [
+            CoxeterDiagram::from(CoxeterDiagramType::A(3)),
+            CoxeterDiagram::from(CoxeterDiagramType::B(3)),
+            CoxeterDiagram::from(CoxeterDiagramType::D(4)),
+            CoxeterDiagram::from(CoxeterDiagramType::F(4)),
+            CoxeterDiagram::from(CoxeterDiagramType::H(3)),
+            CoxeterDiagram::from(CoxeterDiagramType::H(4)),
+            CoxeterDiagram::from(CoxeterDiagramType::E(6)),
+            CoxeterDiagram::from(CoxeterDiagramType::E(7)),
+            CoxeterDiagram::from(CoxeterDiagramType::I2(5)),
+            CoxeterDiagram::new(3, [((1, 2), CoxeterLabel::Finite(3))]).unwrap(),
+        ]
+        .into_iter()
+        .for_each(|coxeter_diagram| {
+            let info = coxeter_diagram.coxeter_group_info().unwrap();
+            let group = CoxeterGroup::new(coxeter_diagram);
+            let rank = group.rank();
+            let invariants = basic_invariants(&group).unwrap();
+            assert_eq!(
+                invariants
+                    .iter()
+                    .map(|invariant| invariant.degree() as u64)
+                    .collect_vec(),
+                info.degrees,
+            );
+            // The checks are exact modulo a prime, where the cyclotomic coefficients reduce
+            // compatibly.
+            let modular = Modular::new(240);
+            let prime = modular.prime;
+            let residue = |value: &Vec<i64>| {
+                modular.residue(
+                    &value
+                        .iter()
+                        .map(|&c| int_cast(c, ZZ, StaticRing::<i64>::RING))
+                        .collect(),
+                )
+            };
+            let schlafli_matrix = group.coxeter_diagram().schlafli_matrix();
+            let schlafli_matrix = (0..rank)
+                .map(|i| {
+                    (0..rank)
+                        .map(|j| residue(schlafli_matrix.at(i, j)))
+                        .collect_vec()
+                })
+                .collect_vec();
+            // The Jacobian is a multiple of the product of the linear forms `B(b, v)` over the
+            // positive roots `b`.
+            let roots = RootSystem::new(&group, usize::MAX)
+                .roots()
+                .iter()
+                .map(|root| {
+                    (0..rank)
+                        .map(|j| {
+                            root.coefficients().iter().enumerate().fold(
+                                0,
+                                |form, (i, coefficient)| {
+                                    (form + residue(coefficient) * schlafli_matrix[i][j]) % prime
+                                },
+                            )
+                        })
+                        .collect_vec()
+                })
+                .collect_vec();
+            let reductions = invariants
+                .iter()
+                .map(|invariant| reduce(&modular, invariant))
+                .collect_vec();
+            let jacobian = invariants
+                .iter()
+                .map(|invariant| {
+                    (0..rank)
+                        .map(|j| reduce(&modular, &invariant.derivative(j)))
+                        .collect_vec()
+                })
+                .collect_vec();
+            let ratios = (1..4)
+                .map(|seed| {
+                    let point = (0..rank)
+                        .map(|j| modular.pow(seed * 7919 + j as u64 * 104729, j as u64 + 2))
+                        .collect_vec();
+                    // Invariance under the generators.
+                    (0..rank).for_each(|k| {
+                        let product = (0..rank).fold(0, |product, j| {
+                            (product + schlafli_matrix[k][j] * point[j]) % prime
+                        });
+                        let mut image = point.clone();
+                        image[k] = (image[k] + prime - product) % prime;
+                        reductions.iter().for_each(|reduction| {
+                            assert_eq!(
+                                evaluate(&modular, reduction, &image),
+                                evaluate(&modular, reduction, &point),
+                            );
+                        });
+                    });
+                    let product = roots.iter().fold(1, |product, form| {
+                        product
+                            * form
+                                .iter()
+                                .zip(&point)
+                                .fold(0, |value, (a, x)| (value + a * x) % prime)
+                            % prime
+                    });
+                    determinant(
+                        &modular,
+                        jacobian
+                            .iter()
+                            .map(|row| {
+                                row.iter()
+                                    .map(|derivative| evaluate(&modular, derivative, &point))
+                                    .collect()
+                            })
+                            .collect(),
+                    ) * modular.pow(product, prime - 2)
+                        % prime
+                })
+                .collect_vec();
+            assert_ne!(ratios[0], 0);
+            assert!(ratios.iter().all(|&ratio| ratio == ratios[0]));
+        });
+    }
+}
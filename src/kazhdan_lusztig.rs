@@ -0,0 +1,351 @@
+use super::coxeter_group::{CoxeterGroup, CoxeterGroupElement};
+use itertools::Itertools;
+use std::collections::{HashMap, VecDeque};
+
+// Polynomials in `q` are given by their coefficients, starting from the constant term and without
+// trailing zeros.
+type Polynomial = Vec<i64>;
+
+// The polynomials `P_{x,w}` of a fixed `w` indexed by `x`, and the pairs `(x, mu(x, w))` with
+// nonzero `mu`.
+type Column = (Vec<Polynomial>, Vec<(usize, i64)>);
+
+fn add_assign_shifted(lhs: &mut Polynomial, rhs: &[i64], shift: usize, factor: i64) {
+    if lhs.len() < rhs.len() + shift {
+        lhs.resize(rhs.len() + shift, 0);
+    }
+    lhs[shift..]
+        .iter_mut()
+        .zip(rhs)
+        .for_each(|(lhs_el, &rhs_el)| *lhs_el += factor * rhs_el);
+    while lhs.last() == Some(&0) {
+        lhs.pop();
+    }
+}
+
+// Elements of the finite group are indexed, and all polynomials are computed on indices. The
+// polynomials `P_{x,w}` are computed for all `x` at once and kept for every `w` met in the
+// recursion.
+pub struct KazhdanLusztig<'a> {
+    group: &'a CoxeterGroup,
+    indices: HashMap<Vec<i64>, usize>,
+    lengths: Vec<usize>,
+    // `products[w][s]` is the index of `ws`.
+    products: Vec<Vec<usize>>,
+    polynomials: Vec<Option<Column>>,
+    r_polynomials: HashMap<(usize, usize), Polynomial>,
+}
+
+impl<'a> KazhdanLusztig<'a> {
+    pub fn new(group: &'a CoxeterGroup) -> Option<Self> {
+        let longest_element = group.longest_element()?;
+        let elements = group.elements_up_to_length(group.length(&longest_element));
+        let indices: HashMap<_, _> = elements
+            .iter()
+            .enumerate()
+            .map(|(index, element)| (group.key(element), index))
+            .collect();
+        let products = elements
+            .iter()
+            .map(|element| {
+                (0..group.rank())
+                    .map(|i| indices[&group.key(&group.mul_generator(group.clone_el(element), i))])
+                    .collect_vec()
+            })
+            .collect_vec();
+        // The length is the distance from the identity in the Cayley graph.
+        let mut lengths = vec![usize::MAX; elements.len()];
+        lengths[0] = 0;
+        let mut queue = VecDeque::from([0]);
+        while let Some(index) = queue.pop_front() {
+            products[index].iter().for_each(|&product| {
+                if lengths[product] == usize::MAX {
+                    lengths[product] = lengths[index] + 1;
+                    queue.push_back(product);
+                }
+            });
+        }
+        Some(Self {
+            group,
+            indices,
+            lengths,
+            products,
+            polynomials: elements.iter().map(|_| None).collect(),
+            r_polynomials: HashMap::new(),
+        })
+    }
+
+    fn index(&self, element: &CoxeterGroupElement) -> usize {
+        self.indices[&self.group.key(element)]
+    }
+
+    fn is_right_descent(&self, w: usize, s: usize) -> bool {
+        self.lengths[self.products[w][s]] < self.lengths[w]
+    }
+
+    // With `s` a right descent of `w` and `v = ws`,
+    // `P_{x,w} = q^(1-c) P_{xs,v} + q^c P_{x,v} - sum_z mu(z, v) q^((l(w)-l(z))/2) P_{x,z}`,
+    // where `c` is 1 if `s` is a right descent of `x` and 0 otherwise, and `z` runs over the
+    // elements with `s` as right descent.
+    fn compute(&mut self, w: usize) {
+        if self.polynomials[w].is_some() {
+            return;
+        }
+        let rank = self.group.rank();
+        let element_count = self.lengths.len();
+        let polynomials = match (0..rank).find(|&s| self.is_right_descent(w, s)) {
+            None => (0..element_count)
+                .map(|x| if x == w { vec![1] } else { vec![] })
+                .collect_vec(),
+            Some(s) => {
+                let v = self.products[w][s];
+                self.compute(v);
+                let mu = self.polynomials[v].as_ref().unwrap().1.clone();
+                let mu = mu
+                    .into_iter()
+                    .filter(|&(z, _)| self.is_right_descent(z, s))
+                    .collect_vec();
+                mu.iter().for_each(|&(z, _)| self.compute(z));
+                let (v_polynomials, _) = self.polynomials[v].as_ref().unwrap();
+                (0..element_count)
+                    .map(|x| {
+                        let c = self.is_right_descent(x, s) as usize;
+                        let mut polynomial = Vec::new();
+                        add_assign_shifted(
+                            &mut polynomial,
+                            &v_polynomials[self.products[x][s]],
+                            1 - c,
+                            1,
+                        );
+                        add_assign_shifted(&mut polynomial, &v_polynomials[x], c, 1);
+                        mu.iter().for_each(|&(z, mu)| {
+                            add_assign_shifted(
+                                &mut polynomial,
+                                &self.polynomials[z].as_ref().unwrap().0[x],
+                                (self.lengths[w] - self.lengths[z]) / 2,
+                                -mu,
+                            );
+                        });
+                        polynomial
+                    })
+                    .collect_vec()
+            }
+        };
+        let mu = (0..element_count)
+            .filter(|&x| {
+                self.lengths[x] < self.lengths[w] && (self.lengths[w] - self.lengths[x]) % 2 == 1
+            })
+            .filter_map(|x| {
+                polynomials[x]
+                    .get((self.lengths[w] - self.lengths[x] - 1) / 2)
+                    .filter(|&&mu| mu != 0)
+                    .map(|&mu| (x, mu))
+            })
+            .collect_vec();
+        self.polynomials[w] = Some((polynomials, mu));
+    }
+
+    pub fn kazhdan_lusztig_polynomial(
+        &mut self,
+        x: &CoxeterGroupElement,
+        w: &CoxeterGroupElement,
+    ) -> Vec<i64> {
+        let x = self.index(x);
+        let w = self.index(w);
+        self.compute(w);
+        self.polynomials[w].as_ref().unwrap().0[x].clone()
+    }
+
+    pub fn mu(&mut self, x: &CoxeterGroupElement, w: &CoxeterGroupElement) -> i64 {
+        let x = self.index(x);
+        let w = self.index(w);
+        self.compute(w);
+        self.polynomials[w]
+            .as_ref()
+            .unwrap()
+            .1
+            .iter()
+            .find(|&&(z, _)| z == x)
+            .map_or(0, |&(_, mu)| mu)
+    }
+
+    pub fn r_polynomial(&mut self, x: &CoxeterGroupElement, w: &CoxeterGroupElement) -> Vec<i64> {
+        let x = self.index(x);
+        let w = self.index(w);
+        self.r_polynomial_by_index(x, w)
+    }
+
+    // With `s` a right descent of `w`, `R_{x,w} = R_{xs,ws}` if `s` is also a right descent of
+    // `x`, and `R_{x,w} = (q-1) R_{x,ws} + q R_{xs,ws}` otherwise.
+    fn r_polynomial_by_index(&mut self, x: usize, w: usize) -> Polynomial {
+        if let Some(polynomial) = self.r_polynomials.get(&(x, w)) {
+            return polynomial.clone();
+        }
+        let polynomial = match (0..self.group.rank()).find(|&s| self.is_right_descent(w, s)) {
+            None => {
+                if x == w {
+                    vec![1]
+                } else {
+                    vec![]
+                }
+            }
+            Some(s) => {
+                let ws = self.products[w][s];
+                let xs = self.products[x][s];
+                if self.is_right_descent(x, s) {
+                    self.r_polynomial_by_index(xs, ws)
+                } else {
+                    let mut polynomial = Vec::new();
+                    let lower = self.r_polynomial_by_index(x, ws);
+                    add_assign_shifted(&mut polynomial, &lower, 1, 1);
+                    add_assign_shifted(&mut polynomial, &lower, 0, -1);
+                    add_assign_shifted(&mut polynomial, &self.r_polynomial_by_index(xs, ws), 1, 1);
+                    polynomial
+                }
+            }
+        };
+        self.r_polynomials.insert((x, w), polynomial.clone());
+        polynomial
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{add_assign_shifted, KazhdanLusztig};
+    use crate::coxeter_diagram::{CoxeterDiagram, CoxeterDiagramType};
+    use crate::coxeter_group::CoxeterGroup;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_kazhdan_lusztig_a3() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::A(3)));
+        let mut kazhdan_lusztig = KazhdanLusztig::new(&group).unwrap();
+        let elements = group.elements_up_to_length(6);
+        // The only singular Schubert varieties of the flag variety of C^4 are those of 3412 and
+        // 4231, singular along the Schubert varieties of 1324 and 2143 respectively.
+        let nontrivial = elements
+            .iter()
+            .cartesian_product(&elements)
+            .filter_map(|(x, w)| {
+                let polynomial = kazhdan_lusztig.kazhdan_lusztig_polynomial(x, w);
+                assert_eq!(polynomial.is_empty(), !group.bruhat_le(x, w));
+                (polynomial.len() > 1).then(|| {
+                    assert_eq!(polynomial, vec![1, 1]);
+                    (group.normal_form(x), group.normal_form(w))
+                })
+            })
+            .collect_vec();
+        assert_eq!(
+            nontrivial,
+            vec![
+                (vec![], vec![1, 0, 2, 1]),
+                (vec![], vec![0, 1, 2, 1, 0]),
+                (vec![0], vec![0, 1, 2, 1, 0]),
+                (vec![1], vec![1, 0, 2, 1]),
+                (vec![2], vec![0, 1, 2, 1, 0]),
+                (vec![0, 2], vec![0, 1, 2, 1, 0]),
+            ],
+        );
+        assert_eq!(
+            kazhdan_lusztig.mu(&group.element(&[1]), &group.element(&[1, 0, 2, 1])),
+            1,
+        );
+        assert_eq!(
+            kazhdan_lusztig.mu(&group.identity(), &group.element(&[0, 1, 2, 1, 0])),
+            0,
+        );
+        assert_eq!(
+            kazhdan_lusztig.r_polynomial(&group.identity(), &group.element(&[0])),
+            vec![-1, 1],
+        );
+        assert_eq!(
+            kazhdan_lusztig.r_polynomial(&group.identity(), &group.element(&[0, 1, 0])),
+            vec![-1, 2, -2, 1],
+        );
+    }
+
+    #[test]
+    fn test_kazhdan_lusztig_inversion() {
+        [CoxeterDiagramType::H(3), CoxeterDiagramType::B(4)]
+            .into_iter()
+            .for_each(|coxeter_diagram_type| {
+                let group = CoxeterGroup::new(CoxeterDiagram::from(coxeter_diagram_type));
+                let mut kazhdan_lusztig = KazhdanLusztig::new(&group).unwrap();
+                let longest_element = group.longest_element().unwrap();
+                let elements = group.bruhat_lower_interval(&longest_element);
+                let lengths = elements.iter().map(|x| group.length(x)).collect_vec();
+                elements.iter().for_each(|x| {
+                    assert_eq!(
+                        kazhdan_lusztig.kazhdan_lusztig_polynomial(x, &longest_element),
+                        vec![1],
+                    );
+                });
+                // `q^(l(w)-l(x)) P_{x,w}(1/q) = sum_y R_{x,y}(q) P_{y,w}(q)` over `x <= y <= w`.
+                let w = group.element(&[0, 1, 2, 1, 0, 1, 2, 1]);
+                let w_length = group.length(&w);
+                elements
+                    .iter()
+                    .zip(&lengths)
+                    .filter(|(x, _)| group.bruhat_le(x, &w))
+                    .for_each(|(x, &x_length)| {
+                        let polynomial = kazhdan_lusztig.kazhdan_lusztig_polynomial(x, &w);
+                        assert_eq!(polynomial[0], 1);
+                        assert!(2 * (polynomial.len() - 1) < (w_length - x_length).max(1));
+                        let mut lhs = vec![0; w_length - x_length + 1];
+                        polynomial
+                            .iter()
+                            .enumerate()
+                            .for_each(|(degree, &coefficient)| {
+                                lhs[w_length - x_length - degree] = coefficient;
+                            });
+                        let mut rhs = Vec::new();
+                        elements.iter().for_each(|y| {
+                            let r_polynomial = kazhdan_lusztig.r_polynomial(x, y);
+                            let polynomial = kazhdan_lusztig.kazhdan_lusztig_polynomial(y, &w);
+                            r_polynomial
+                                .iter()
+                                .enumerate()
+                                .for_each(|(degree, &coefficient)| {
+                                    add_assign_shifted(&mut rhs, &polynomial, degree, coefficient);
+                                });
+                        });
+                        assert_eq!(lhs, rhs);
+                    });
+            });
+    }
+
+    #[test]
+    fn test_kazhdan_lusztig_f4() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::F(4)));
+        let mut kazhdan_lusztig = KazhdanLusztig::new(&group).unwrap();
+        let longest_element = group.longest_element().unwrap();
+        assert_eq!(
+            kazhdan_lusztig.kazhdan_lusztig_polynomial(&group.identity(), &longest_element),
+            vec![1],
+        );
+        assert_eq!(
+            kazhdan_lusztig
+                .kazhdan_lusztig_polynomial(&group.element(&[1, 2, 1, 2]), &longest_element),
+            vec![1],
+        );
+        // `P_{x,w} = 1 + q` for `w = s_b s_a s_c s_b` with `a` and `c` commuting and both joined to
+        // `b`, exactly for `x <= s_b`, on either side of the label 4.
+        [[1, 0, 2, 1], [2, 1, 3, 2]].into_iter().for_each(|word| {
+            let w = group.element(&word);
+            [
+                (vec![], vec![1, 1]),
+                (vec![word[0]], vec![1, 1]),
+                (vec![word[1]], vec![1]),
+                (vec![word[1], word[2]], vec![1]),
+                (word[1..].to_vec(), vec![1]),
+            ]
+            .into_iter()
+            .for_each(|(x, polynomial)| {
+                assert_eq!(
+                    kazhdan_lusztig.kazhdan_lusztig_polynomial(&group.element(&x), &w),
+                    polynomial,
+                );
+            });
+        });
+    }
+}
@@ -1,4 +1,14 @@
+pub mod automaton;
+pub mod character_table;
 pub mod coxeter_diagram;
+pub mod coxeter_group;
+pub mod coxeter_plane;
 pub mod cyclotomic;
 pub mod dynkin_diagram;
+pub mod invariants;
+pub mod kazhdan_lusztig;
+mod numeric;
+pub mod root_system;
 pub mod square_matrix;
+pub mod tiling;
+pub mod uniform_polytope;
@@ -0,0 +1,101 @@
+use super::coxeter_group::{CyclotomicRing, Matrix};
+use super::cyclotomic::cyclotomic_numeric_embed_into;
+use feanor_math::{
+    ring::{El, RingStore},
+    rings::float_complex::Complex64,
+};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+// The real part under the embedding `zeta_N -> e^(2 pi i / N)`.
+pub(crate) fn numeric(cr: &CyclotomicRing, value: &El<CyclotomicRing>) -> f64 {
+    Complex64::RING.re(cyclotomic_numeric_embed_into(cr, cr.clone_el(value)))
+}
+
+pub(crate) fn numeric_matrix(cr: &CyclotomicRing, matrix: &Matrix, rank: usize) -> Vec<Vec<f64>> {
+    (0..rank)
+        .map(|i| (0..rank).map(|j| numeric(cr, matrix.at(i, j))).collect())
+        .collect()
+}
+
+// Points computed along different paths are identified by their coordinates rounded to six
+// decimals.
+pub(crate) fn point_key(point: &[f64]) -> Vec<i64> {
+    point.iter().map(|x| (x * 1e6).round() as i64).collect()
+}
+
+// `s_j(p) = p - p_j a_j` for a point in fundamental weight coordinates, where `a_j` is row `j` of
+// the Schlafli matrix.
+pub(crate) fn reflect(schlafli_matrix: &[Vec<f64>], point: &[f64], j: usize) -> Vec<f64> {
+    point
+        .iter()
+        .zip(&schlafli_matrix[j])
+        .map(|(x, a)| x - point[j] * a)
+        .collect()
+}
+
+// The orbit of points in fundamental weight coordinates under a finite group.
+pub(crate) fn weight_orbit(schlafli_matrix: &[Vec<f64>], points: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let mut keys = HashSet::new();
+    let mut orbit = points
+        .iter()
+        .filter(|point| keys.insert(point_key(point)))
+        .cloned()
+        .collect_vec();
+    let mut index = 0;
+    while index < orbit.len() {
+        (0..schlafli_matrix.len()).for_each(|j| {
+            let image = reflect(schlafli_matrix, &orbit[index], j);
+            if keys.insert(point_key(&image)) {
+                orbit.push(image);
+            }
+        });
+        index += 1;
+    }
+    orbit
+}
+
+// The orthonormal eigenvectors of a real symmetric matrix by cyclic Jacobi rotations.
+pub(crate) fn symmetric_eigenvectors(mut matrix: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let size = matrix.len();
+    let mut eigenvectors = (0..size)
+        .map(|i| (0..size).map(|j| (i == j) as u8 as f64).collect_vec())
+        .collect_vec();
+    for _ in 0..100 {
+        let off_diagonal = (0..size)
+            .flat_map(|i| (0..size).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| matrix[i][j] * matrix[i][j])
+            .sum::<f64>();
+        if off_diagonal < 1e-22 {
+            break;
+        }
+        (0..size).tuple_combinations().for_each(|(p, q)| {
+            if matrix[p][q].abs() < 1e-300 {
+                return;
+            }
+            let theta = (matrix[q][q] - matrix[p][p]) / (2.0 * matrix[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+            (0..size).for_each(|k| {
+                let (a, b) = (matrix[k][p], matrix[k][q]);
+                matrix[k][p] = c * a - s * b;
+                matrix[k][q] = s * a + c * b;
+            });
+            (0..size).for_each(|k| {
+                let (a, b) = (matrix[p][k], matrix[q][k]);
+                matrix[p][k] = c * a - s * b;
+                matrix[q][k] = s * a + c * b;
+            });
+            eigenvectors.iter_mut().for_each(|eigenvector| {
+                let (a, b) = (eigenvector[p], eigenvector[q]);
+                eigenvector[p] = c * a - s * b;
+                eigenvector[q] = s * a + c * b;
+            });
+        });
+    }
+    // Column `j` of the accumulated rotations is the `j`-th eigenvector.
+    (0..size)
+        .map(|j| (0..size).map(|i| eigenvectors[i][j]).collect())
+        .collect()
+}
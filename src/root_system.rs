@@ -0,0 +1,491 @@
+use super::coxeter_group::{CoxeterGroup, CoxeterGroupElement, CyclotomicRing, Matrix};
+use super::cyclotomic::cyclotomic_sign;
+use feanor_math::{
+    homomorphism::Homomorphism,
+    ring::{El, RingBase, RingStore},
+};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+// A positive root `w(a_i)` given by its coefficients in the simple roots `a_i`.
+pub struct Root {
+    coefficients: Vec<El<CyclotomicRing>>,
+    depth: usize,
+    word: Vec<usize>,
+    index: usize,
+}
+
+impl Root {
+    pub fn coefficients(&self) -> &[El<CyclotomicRing>] {
+        &self.coefficients
+    }
+
+    // The least length of an element sending the root to a negative one.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+// The positive roots are generated by depth: for a root `b` with `B(b, a_i) < 0` under the Schlafli
+// form `B`, the root `s_i(b) = b - B(b, a_i) a_i` is one deeper, and every root of depth at least 2
+// arises this way.
+pub struct RootSystem<'a> {
+    group: &'a CoxeterGroup,
+    schlafli_matrix: Matrix,
+    roots: Vec<Root>,
+}
+
+impl<'a> RootSystem<'a> {
+    // All positive roots up to the given depth, so `usize::MAX` gives all of them for finite
+    // groups. Roots are sorted by depth.
+    pub fn new(group: &'a CoxeterGroup, max_depth: usize) -> Self {
+        Self::generate(group, max_depth, |_| true)
+    }
+
+    // The small roots, those obtained from simple roots by reflections `s_i` with
+    // `-2 < B(b, a_i) < 0`, of which there are finitely many (Brink-Howlett).
+    pub fn small_roots(group: &'a CoxeterGroup) -> Self {
+        let cr = group.cyclotomic_ring();
+        Self::generate(group, usize::MAX, |product| {
+            cyclotomic_sign(cr, &cr.add_ref_fst(product, cr.int_hom().map(2)))
+                == std::cmp::Ordering::Greater
+        })
+    }
+
+    fn generate<P>(group: &'a CoxeterGroup, max_depth: usize, predicate: P) -> Self
+    where
+        P: Fn(&El<CyclotomicRing>) -> bool,
+    {
+        let rank = group.rank();
+        let cr = group.cyclotomic_ring();
+        let schlafli_matrix = group.coxeter_diagram().schlafli_matrix();
+        let mut roots = (0..rank)
+            .filter(|_| max_depth > 0)
+            .map(|index| Root {
+                coefficients: (0..rank)
+                    .map(|j| cr.get_ring().from_int((j == index) as i32))
+                    .collect(),
+                depth: 1,
+                word: Vec::new(),
+                index,
+            })
+            .collect_vec();
+        let mut level = 0..roots.len();
+        let mut depth = 1;
+        while depth < max_depth && !level.is_empty() {
+            let mut keys = HashSet::new();
+            let next_level = (0..rank)
+                .flat_map(|i| {
+                    let schlafli_matrix = &schlafli_matrix;
+                    let predicate = &predicate;
+                    roots[level.clone()].iter().filter_map(move |root| {
+                        let product = schlafli_form(cr, schlafli_matrix, i, &root.coefficients);
+                        (cyclotomic_sign(cr, &product) == std::cmp::Ordering::Less
+                            && predicate(&product))
+                        .then(|| {
+                            let mut coefficients = root
+                                .coefficients
+                                .iter()
+                                .map(|coefficient| cr.clone_el(coefficient))
+                                .collect_vec();
+                            cr.sub_assign(&mut coefficients[i], product);
+                            Root {
+                                coefficients,
+                                depth: depth + 1,
+                                word: std::iter::once(i)
+                                    .chain(root.word.iter().copied())
+                                    .collect(),
+                                index: root.index,
+                            }
+                        })
+                    })
+                })
+                .filter(|root| keys.insert(root_key(group, &root.coefficients)))
+                .collect_vec();
+            level = roots.len()..roots.len() + next_level.len();
+            roots.extend(next_level);
+            depth += 1;
+        }
+        Self {
+            group,
+            schlafli_matrix,
+            roots,
+        }
+    }
+
+    // The coefficients of `s_i(b)`.
+    pub fn reflect(
+        &self,
+        i: usize,
+        coefficients: &[El<CyclotomicRing>],
+    ) -> Vec<El<CyclotomicRing>> {
+        let cr = self.group.cyclotomic_ring();
+        let product = schlafli_form(cr, &self.schlafli_matrix, i, coefficients);
+        let mut coefficients = coefficients
+            .iter()
+            .map(|coefficient| cr.clone_el(coefficient))
+            .collect_vec();
+        cr.sub_assign(&mut coefficients[i], product);
+        coefficients
+    }
+
+    pub fn roots(&self) -> &[Root] {
+        &self.roots
+    }
+
+    // The height, which for crystallographic diagrams is the sum of the integral coefficients on
+    // the simple roots `l_i a_i` (see `simple_root_lengths`) and the rank function of the root
+    // poset. For other diagrams it is the sum of the coefficients on the `a_i`.
+    pub fn height(&self, root: &Root) -> El<CyclotomicRing> {
+        let cr = self.group.cyclotomic_ring();
+        if !self.group.coxeter_diagram().is_crystallographic() {
+            return cr.sum(
+                root.coefficients
+                    .iter()
+                    .map(|coefficient| cr.clone_el(coefficient)),
+            );
+        }
+        let lengths = self.simple_root_lengths();
+        // The coefficient `l_k c_i / l_i` of the root `w(a_k)` is a rational integer, found as the
+        // constant term of the reduced form of `l_k c_i l_i` divided by `l_i^2`.
+        let height = root
+            .coefficients
+            .iter()
+            .zip(&lengths)
+            .map(|(coefficient, length)| {
+                let numerator = cr.mul(
+                    cr.mul_ref(coefficient, &lengths[root.index]),
+                    cr.clone_el(length),
+                );
+                self.group.reduced(&numerator)[0]
+                    / self.group.reduced(&cr.mul_ref(length, length))[0]
+            })
+            .sum::<i64>();
+        vec![height]
+    }
+
+    // The reflection `w s_i w^-1` in the root `w(a_i)`.
+    pub fn reflection(&self, root: &Root) -> CoxeterGroupElement {
+        self.group.element(
+            &root
+                .word
+                .iter()
+                .copied()
+                .chain(std::iter::once(root.index))
+                .chain(root.word.iter().rev().copied())
+                .collect_vec(),
+        )
+    }
+
+    // The Schlafli form, under which every root has norm 2.
+    pub fn inner_product(&self, lhs: &Root, rhs: &Root) -> El<CyclotomicRing> {
+        let cr = self.group.cyclotomic_ring();
+        cr.sum(lhs.coefficients.iter().enumerate().flat_map(|(i, lhs_el)| {
+            rhs.coefficients.iter().enumerate().map(move |(j, rhs_el)| {
+                cr.mul(
+                    cr.mul_ref(lhs_el, rhs_el),
+                    cr.clone_el(self.schlafli_matrix.at(i, j)),
+                )
+            })
+        }))
+    }
+
+    // The covering relations `(i, j)` of the root poset, where `b <= c` if `c - b` is a
+    // nonnegative combination of simple roots. Only crystallographic diagrams are supported.
+    pub fn root_poset(&self) -> Option<Vec<(usize, usize)>> {
+        if !self.group.coxeter_diagram().is_crystallographic() {
+            return None;
+        }
+        let cr = self.group.cyclotomic_ring();
+        let lengths = self.simple_root_lengths();
+        // The root `w(a_k)` of norm 2 is `l_k / l_i` times the crystallographic coefficient of the
+        // simple root `l_i a_i`, so coefficients are compared after scaling by `l_k`.
+        let coefficients = self
+            .roots
+            .iter()
+            .map(|root| {
+                root.coefficients
+                    .iter()
+                    .map(|coefficient| cr.mul_ref(coefficient, &lengths[root.index]))
+                    .collect_vec()
+            })
+            .collect_vec();
+        let less_than = (0..self.roots.len())
+            .map(|i| {
+                (0..self.roots.len())
+                    .map(|j| {
+                        i != j
+                            && coefficients[i]
+                                .iter()
+                                .zip(&coefficients[j])
+                                .all(|(lhs, rhs)| {
+                                    cyclotomic_sign(cr, &cr.sub_ref(rhs, lhs))
+                                        != std::cmp::Ordering::Less
+                                })
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+        Some(
+            (0..self.roots.len())
+                .cartesian_product(0..self.roots.len())
+                .filter(|&(i, j)| {
+                    less_than[i][j]
+                        && !(0..self.roots.len()).any(|k| less_than[i][k] && less_than[k][j])
+                })
+                .collect(),
+        )
+    }
+
+    // The lengths `l_i` of the simple roots of a crystallographic root system with this diagram,
+    // relative to the short roots of each component. They change by the factor `-B(a_i, a_j)`,
+    // which is `sqrt(2)` or `sqrt(3)`, across the edges labelled 4 or 6. The diagram does not tell
+    // `B_n` from `C_n`, whose root posets are isomorphic, so the first node of each component is
+    // taken to be short.
+    fn simple_root_lengths(&self) -> Vec<El<CyclotomicRing>> {
+        let cr = self.group.cyclotomic_ring();
+        let rank = self.group.rank();
+        let mut lengths = (0..rank).map(|_| None).collect_vec();
+        self.group
+            .coxeter_diagram()
+            .connected_components()
+            .iter()
+            .for_each(|component| {
+                lengths[component[0]] = Some((false, cr.one()));
+                let mut stack = vec![component[0]];
+                while let Some(i) = stack.pop() {
+                    (0..rank).for_each(|j| {
+                        let product = self.schlafli_matrix.at(i, j);
+                        if lengths[j].is_some() || cr.is_zero(product) {
+                            return;
+                        }
+                        let square = cr.mul_ref(product, product);
+                        let (long, length) = lengths[i].as_ref().unwrap();
+                        lengths[j] = Some(
+                            if cr.eq_el(&square, &cr.int_hom().map(2))
+                                || cr.eq_el(&square, &cr.int_hom().map(3))
+                            {
+                                if *long {
+                                    (false, cr.one())
+                                } else {
+                                    (true, cr.negate(cr.clone_el(product)))
+                                }
+                            } else {
+                                (*long, cr.clone_el(length))
+                            },
+                        );
+                        stack.push(j);
+                    });
+                }
+            });
+        lengths
+            .into_iter()
+            .map(|length| length.unwrap().1)
+            .collect()
+    }
+}
+
+// `B(a_i, b)` for the root `b` with the given coefficients.
+fn schlafli_form(
+    cr: &CyclotomicRing,
+    schlafli_matrix: &Matrix,
+    i: usize,
+    coefficients: &[El<CyclotomicRing>],
+) -> El<CyclotomicRing> {
+    cr.sum(
+        coefficients
+            .iter()
+            .enumerate()
+            .map(|(j, coefficient)| cr.mul_ref(schlafli_matrix.at(i, j), coefficient)),
+    )
+}
+
+pub(crate) fn root_key(group: &CoxeterGroup, coefficients: &[El<CyclotomicRing>]) -> Vec<Vec<i64>> {
+    coefficients
+        .iter()
+        .map(|coefficient| group.reduced(coefficient))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::RootSystem;
+    use crate::coxeter_diagram::{CoxeterDiagram, CoxeterDiagramType, CoxeterLabel};
+    use crate::coxeter_group::CoxeterGroup;
+    use crate::cyclotomic::cyclotomic_sign;
+    use feanor_math::{homomorphism::Homomorphism, ring::RingStore};
+    use itertools::Itertools;
+
+    #[test]
+    fn test_finite_root_system() {
+        [
+            CoxeterDiagramType::A(4),
+            CoxeterDiagramType::B(4),
+            CoxeterDiagramType::D(5),
+            CoxeterDiagramType::E(6),
+            CoxeterDiagramType::F(4),
+            CoxeterDiagramType::G(2),
+            CoxeterDiagramType::H(3),
+            CoxeterDiagramType::H(4),
+            CoxeterDiagramType::I2(7),
+        ]
+        .into_iter()
+        .for_each(|coxeter_diagram_type| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            let info = coxeter_diagram.coxeter_group_info().unwrap();
+            let group = CoxeterGroup::new(coxeter_diagram);
+            let root_system = RootSystem::new(&group, usize::MAX);
+            assert_eq!(
+                root_system.roots().len() as u64,
+                group.rank() as u64 * info.coxeter_number / 2,
+            );
+            let reflections = root_system
+                .roots()
+                .iter()
+                .map(|root| root_system.reflection(root))
+                .collect_vec();
+            assert_eq!(
+                reflections
+                    .iter()
+                    .map(|reflection| group.key(reflection))
+                    .unique()
+                    .count(),
+                root_system.roots().len(),
+            );
+            root_system
+                .roots()
+                .iter()
+                .zip(&reflections)
+                .for_each(|(root, reflection)| {
+                    assert!(group.is_identity(&group.mul(reflection, reflection)));
+                    assert_eq!(group.length(reflection) % 2, 1);
+                    assert_eq!(group.length(reflection), 2 * root.depth() - 1);
+                    assert!(root
+                        .coefficients()
+                        .iter()
+                        .all(
+                            |coefficient| cyclotomic_sign(group.cyclotomic_ring(), coefficient)
+                                != std::cmp::Ordering::Less
+                        ));
+                    assert!(group.cyclotomic_ring().eq_el(
+                        &root_system.inner_product(root, root),
+                        &group.cyclotomic_ring().int_hom().map(2),
+                    ));
+                });
+        });
+    }
+
+    #[test]
+    fn test_root_poset() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::A(3)));
+        let root_system = RootSystem::new(&group, usize::MAX);
+        assert_eq!(root_system.root_poset().unwrap().len(), 6);
+        // The root poset of E6 has the simple roots as minimal elements and the highest root,
+        // of height 11, as its only maximal element.
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::E(6)));
+        let root_system = RootSystem::new(&group, usize::MAX);
+        let root_poset = root_system.root_poset().unwrap();
+        let roots = root_system.roots();
+        assert_eq!(
+            (0..roots.len())
+                .filter(|&i| root_poset.iter().all(|&(_, j)| j != i))
+                .count(),
+            6,
+        );
+        let maximal = (0..roots.len())
+            .filter(|&i| root_poset.iter().all(|&(j, _)| j != i))
+            .collect_vec();
+        assert_eq!(maximal.len(), 1);
+        assert!(group.cyclotomic_ring().eq_el(
+            &root_system.height(&roots[maximal[0]]),
+            &group.cyclotomic_ring().int_hom().map(11),
+        ));
+        root_poset.iter().for_each(|&(i, j)| {
+            assert!(group.cyclotomic_ring().eq_el(
+                &root_system.height(&roots[j]),
+                &group
+                    .cyclotomic_ring()
+                    .add(root_system.height(&roots[i]), group.cyclotomic_ring().one()),
+            ));
+        });
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::H(3)));
+        assert!(RootSystem::new(&group, usize::MAX).root_poset().is_none());
+        // In the non-simply laced cases the poset is graded by the crystallographic height, with
+        // `rank` roots at the bottom and the highest root of height `h - 1` at the top.
+        [
+            (CoxeterDiagramType::B(2), vec![2, 1, 1]),
+            (CoxeterDiagramType::G(2), vec![2, 1, 1, 1, 1]),
+            (CoxeterDiagramType::B(3), vec![3, 2, 2, 1, 1]),
+            (
+                CoxeterDiagramType::F(4),
+                vec![4, 3, 3, 3, 3, 2, 2, 1, 1, 1, 1],
+            ),
+        ]
+        .into_iter()
+        .for_each(|(coxeter_diagram_type, level_sizes)| {
+            let coxeter_diagram = CoxeterDiagram::from(coxeter_diagram_type);
+            let coxeter_number = coxeter_diagram.coxeter_group_info().unwrap().coxeter_number;
+            let group = CoxeterGroup::new(coxeter_diagram);
+            let root_system = RootSystem::new(&group, usize::MAX);
+            let root_poset = root_system.root_poset().unwrap();
+            let heights = root_system
+                .roots()
+                .iter()
+                .map(|root| {
+                    let height = group.reduced(&root_system.height(root));
+                    assert!(height[1..].iter().all(|&c| c == 0));
+                    height[0]
+                })
+                .collect_vec();
+            assert!(root_poset
+                .iter()
+                .all(|&(i, j)| heights[j] == heights[i] + 1));
+            assert_eq!(
+                heights
+                    .iter()
+                    .counts()
+                    .into_iter()
+                    .sorted()
+                    .map(|(_, count)| count)
+                    .collect_vec(),
+                level_sizes,
+            );
+            assert_eq!(heights.iter().max(), Some(&(coxeter_number as i64 - 1)));
+        });
+    }
+
+    #[test]
+    fn test_infinite_root_system() {
+        let group = CoxeterGroup::new(CoxeterDiagram::from(CoxeterDiagramType::AffineA(1)));
+        let root_system = RootSystem::new(&group, 5);
+        assert_eq!(root_system.roots().len(), 10);
+        assert!(root_system
+            .roots()
+            .iter()
+            .map(|root| root.depth())
+            .counts()
+            .values()
+            .all(|&count| count == 2));
+        // The (2, 3, 7) triangle group.
+        let group = CoxeterGroup::new(
+            CoxeterDiagram::new(
+                3,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((1, 2), CoxeterLabel::Finite(7)),
+                ],
+            )
+            .unwrap(),
+        );
+        let root_system = RootSystem::new(&group, 6);
+        root_system.roots().iter().for_each(|root| {
+            let reflection = root_system.reflection(root);
+            assert!(group.is_identity(&group.mul(&reflection, &reflection)));
+            assert_eq!(group.length(&reflection), 2 * root.depth() - 1);
+        });
+        assert_eq!(
+            root_system.roots().iter().map(|root| root.depth()).max(),
+            Some(6),
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use feanor_math::{
     matrix::OwnedMatrix,
-    ring::{El, RingBase, RingStore},
+    ring::{El, RingBase, RingStore, RingValue},
+    rings::poly::{dense_poly::DensePolyRing, PolyRingStore},
 };
 use itertools::Itertools;
 
@@ -98,34 +99,34 @@ where
         out: &mut std::fmt::Formatter<'a>,
         env: feanor_math::ring::EnvBindingStrength,
     ) -> std::fmt::Result {
-        out.write_str(&"[")?;
+        out.write_str("[")?;
         let mut row_iter = value.data().row_iter();
         if let Some(first_row) = row_iter.next() {
-            out.write_str(&"[")?;
-            let mut el_iter = first_row.into_iter();
+            out.write_str("[")?;
+            let mut el_iter = first_row.iter();
             if let Some(first_el) = el_iter.next() {
                 self.base_ring.get_ring().dbg_within(first_el, out, env)?;
             }
             for el in el_iter {
-                out.write_str(&", ")?;
+                out.write_str(", ")?;
                 self.base_ring.get_ring().dbg_within(el, out, env)?;
             }
-            out.write_str(&"]")?;
+            out.write_str("]")?;
         }
         for row in row_iter {
-            out.write_str(&", ")?;
-            out.write_str(&"[")?;
-            let mut el_iter = row.into_iter();
+            out.write_str(", ")?;
+            out.write_str("[")?;
+            let mut el_iter = row.iter();
             if let Some(first_el) = el_iter.next() {
                 self.base_ring.get_ring().dbg_within(first_el, out, env)?;
             }
             for el in el_iter {
-                out.write_str(&", ")?;
+                out.write_str(", ")?;
                 self.base_ring.get_ring().dbg_within(el, out, env)?;
             }
-            out.write_str(&"]")?;
+            out.write_str("]")?;
         }
-        out.write_str(&"]\n")?;
+        out.write_str("]\n")?;
         Ok(())
     }
 
@@ -147,31 +148,62 @@ where
 {
     let dimension = ring.get_ring().dimension;
     let base_ring = &ring.get_ring().base_ring;
-    base_ring.sum(
-        (0..dimension)
-            .permutations(dimension)
-            .filter(|permutation| {
-                (0..dimension).all(|i| !base_ring.is_zero(matrix.at(i, permutation[i])))
-            })
-            .map(|permutation| {
-                let neg_sign = permutation
+    let nonzero_columns = (0..dimension)
+        .map(|i| {
+            (0..dimension)
+                .filter(|&j| !base_ring.is_zero(matrix.at(i, j)))
+                .collect_vec()
+        })
+        .collect_vec();
+    // Laplace expansion along the rows, memoized on the set of columns already used: the entry
+    // at `mask` is the minor on the last rows and the columns outside of `mask`. Supersets of
+    // `mask` are numerically larger, so they are always computed first.
+    let mut minors = std::iter::repeat_with(|| None)
+        .take(1 << dimension)
+        .collect_vec();
+    minors[(1 << dimension) - 1] = Some(base_ring.one());
+    (0..(1_usize << dimension) - 1).rev().for_each(|mask| {
+        let row = mask.count_ones() as usize;
+        minors[mask] = Some(
+            base_ring.sum(
+                nonzero_columns[row]
                     .iter()
-                    .combinations(2)
-                    .map(|index_pair| {
-                        if let [i, j] = index_pair.as_slice() {
-                            i > j
+                    .filter(|&&j| mask & (1 << j) == 0)
+                    .map(|&j| {
+                        let product = base_ring
+                            .mul_ref(matrix.at(row, j), minors[mask | (1 << j)].as_ref().unwrap());
+                        if (!mask & ((1 << j) - 1)).count_ones() % 2 == 1 {
+                            base_ring.negate(product)
                         } else {
-                            unreachable!();
+                            product
                         }
                     })
-                    .fold(false, std::ops::BitXor::bitxor);
-                let product = base_ring
-                    .prod((0..dimension).map(|i| base_ring.clone_el(matrix.at(i, permutation[i]))));
-                if neg_sign {
-                    base_ring.negate(product)
-                } else {
-                    product
-                }
-            }),
+                    .collect_vec(),
+            ),
+        );
+    });
+    minors.swap_remove(0).unwrap()
+}
+
+pub fn characteristic_polynomial<R>(
+    ring: &impl RingStore<Type = SquareMatrixRingBase<R>>,
+    poly_ring: &DensePolyRing<R>,
+    mut matrix: OwnedMatrix<El<R>>,
+) -> El<DensePolyRing<R>>
+where
+    R: RingStore + Clone,
+{
+    let dimension = ring.get_ring().dimension;
+    let base_ring = &ring.get_ring().base_ring;
+    determinant(
+        &RingValue::from(SquareMatrixRingBase::new(poly_ring.clone(), dimension)),
+        OwnedMatrix::from_fn(dimension, dimension, |i, j| {
+            let el = base_ring.negate(std::mem::replace(matrix.at_mut(i, j), base_ring.zero()));
+            if i == j {
+                poly_ring.from_terms([(el, 0), (base_ring.one(), 1)])
+            } else {
+                poly_ring.from_terms([(el, 0)])
+            }
+        }),
     )
 }
@@ -0,0 +1,290 @@
+use super::coxeter_diagram::{CoxeterDiagram, CoxeterGroupType};
+use super::coxeter_group::CoxeterGroup;
+use super::numeric::{numeric_matrix, point_key, reflect, symmetric_eigenvectors};
+use itertools::Itertools;
+use std::collections::{BTreeSet, HashMap};
+
+// A finite patch of the uniform tiling of the Euclidean or hyperbolic plane given by Wythoff's
+// construction for a connected rank 3 diagram, made of the images `w(p)` for `l(w)` up to a bound,
+// with `p` as in `UniformPolytope`. Points are handled in fundamental weight coordinates, which are
+// mapped to the plane through an orthonormal eigenbasis of the Schlafli form: for parabolic
+// diagrams the null direction measures the level and the two others give Euclidean coordinates,
+// and for hyperbolic ones the points are normalized onto the hyperboloid and sent to the Poincare
+// disk.
+pub struct Tiling {
+    vertices: Vec<[f64; 2]>,
+    edges: Vec<(usize, usize)>,
+    tiles: Vec<Vec<usize>>,
+}
+
+impl Tiling {
+    // Returns `None` unless the diagram is connected of rank 3 and parabolic or hyperbolic, or if
+    // the vertex `p` lies on or beyond the boundary of the hyperbolic plane.
+    pub fn new(
+        coxeter_diagram: CoxeterDiagram,
+        ringed: &[usize],
+        max_length: usize,
+    ) -> Option<Self> {
+        let rank = coxeter_diagram.rank();
+        let coxeter_group_type = coxeter_diagram.coxeter_group_type();
+        if rank != 3
+            || ringed.is_empty()
+            || ringed.iter().any(|&i| i >= rank)
+            || coxeter_diagram.connected_components().len() != 1
+            || !matches!(
+                coxeter_group_type,
+                CoxeterGroupType::Parabolic
+                    | CoxeterGroupType::Hyperbolic
+                    | CoxeterGroupType::CompactHyperbolic
+            )
+        {
+            return None;
+        }
+        let schlafli_matrix = coxeter_diagram.numeric_schlafli_matrix();
+        let point = (0..rank)
+            .map(|i| ringed.contains(&i) as u8 as f64)
+            .collect_vec();
+        // The eigenvectors sorted by decreasing eigenvalue, the last one being null or negative
+        // and oriented towards the fundamental chamber.
+        let eigenvectors = symmetric_eigenvectors(schlafli_matrix.clone())
+            .into_iter()
+            .map(|eigenvector| {
+                let eigenvalue = (0..rank)
+                    .map(|i| {
+                        eigenvector[i]
+                            * (0..rank)
+                                .map(|j| schlafli_matrix[i][j] * eigenvector[j])
+                                .sum::<f64>()
+                    })
+                    .sum::<f64>();
+                (eigenvalue, eigenvector)
+            })
+            .sorted_by(|(lhs, _), (rhs, _)| rhs.total_cmp(lhs))
+            .collect_vec();
+        let dot = |lhs: &[f64], rhs: &[f64]| lhs.iter().zip(rhs).map(|(x, y)| x * y).sum::<f64>();
+        let orientation = dot(&eigenvectors[2].1, &point).signum();
+        let project = |point: &[f64]| -> Option<[f64; 2]> {
+            let [x, y, z] = [0, 1, 2].map(|k| {
+                let (eigenvalue, eigenvector) = &eigenvectors[k];
+                let coordinate = dot(eigenvector, point);
+                if coxeter_group_type == CoxeterGroupType::Parabolic && k == 2 {
+                    coordinate * orientation
+                } else {
+                    coordinate / eigenvalue.abs().sqrt()
+                }
+            });
+            if coxeter_group_type == CoxeterGroupType::Parabolic {
+                return Some([x / z, y / z]);
+            }
+            let z = z * orientation;
+            let norm = z * z - x * x - y * y;
+            (norm > 1e-9).then(|| {
+                let norm = norm.sqrt();
+                [x / norm, y / norm].map(|coordinate| coordinate / (1.0 + z / norm))
+            })
+        };
+        project(&point)?;
+        let group = CoxeterGroup::new(coxeter_diagram);
+        let cr = group.cyclotomic_ring();
+        let mut indices = HashMap::new();
+        let mut vertices = Vec::new();
+        let mut edges = BTreeSet::new();
+        let mut tiles = BTreeSet::new();
+        let elements = group
+            .elements_up_to_length(max_length)
+            .iter()
+            .map(|element| numeric_matrix(cr, group.matrix(element), rank))
+            .collect_vec();
+        let apply = |matrix: &[Vec<f64>], point: &[f64]| {
+            matrix.iter().map(|row| dot(row, point)).collect_vec()
+        };
+        elements.iter().for_each(|matrix| {
+            let vertex = apply(matrix, &point);
+            indices.entry(point_key(&vertex)).or_insert_with(|| {
+                vertices.push(project(&vertex).unwrap());
+                vertices.len() - 1
+            });
+        });
+        let index = |point: &[f64]| indices.get(&point_key(point)).copied();
+        // The polygons `W_J p` for the pairs `J` of nodes with a finite label `m` containing a ringed
+        // node, in the cyclic order `p, s_i p, s_i s_j p, ...` of the words of length below `2m`.
+        let polygons = (0..rank)
+            .tuple_combinations()
+            .filter(|&(i, j)| ringed.contains(&i) || ringed.contains(&j))
+            .filter_map(|(i, j)| {
+                // The dihedral group of order `2m`, or `None` for an infinite label.
+                let label = group
+                    .coxeter_diagram()
+                    .subdiagram(&[i, j])
+                    .coxeter_group_info()?
+                    .order
+                    / 2;
+                let mut polygon = (0..2 * label)
+                    .map(|length| {
+                        (0..length).rev().fold(point.clone(), |vertex, position| {
+                            let node = if position % 2 == 0 { i } else { j };
+                            reflect(&schlafli_matrix, &vertex, node)
+                        })
+                    })
+                    .dedup_by(|lhs, rhs| point_key(lhs) == point_key(rhs))
+                    .collect_vec();
+                if point_key(polygon.last().unwrap()) == point_key(&point) {
+                    polygon.pop();
+                }
+                Some(polygon)
+            })
+            .collect_vec();
+        elements.iter().for_each(|matrix| {
+            let vertex = index(&apply(matrix, &point)).unwrap();
+            ringed.iter().for_each(|&j| {
+                let image = apply(matrix, &reflect(&schlafli_matrix, &point, j));
+                if let Some(neighbor) = index(&image) {
+                    edges.insert((vertex.min(neighbor), vertex.max(neighbor)));
+                }
+            });
+            polygons.iter().for_each(|polygon| {
+                if let Some(tile) = polygon
+                    .iter()
+                    .map(|vertex| index(&apply(matrix, vertex)))
+                    .collect::<Option<Vec<_>>>()
+                {
+                    if tile.len() >= 3 {
+                        tiles.insert(normalized_cycle(tile));
+                    }
+                }
+            });
+        });
+        Some(Self {
+            vertices,
+            edges: edges.into_iter().collect(),
+            tiles: tiles.into_iter().collect(),
+        })
+    }
+
+    // Coordinates in the Euclidean plane or the Poincare disk, the first vertex being `p`.
+    pub fn vertices(&self) -> &[[f64; 2]] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    // The tiles whose vertices all lie in the patch, as cycles of vertex indices.
+    pub fn tiles(&self) -> &[Vec<usize>] {
+        &self.tiles
+    }
+}
+
+// The same cycle is reached from different elements with every rotation and direction, so it is
+// rotated to start at its least vertex and read towards the lesser of its two neighbors.
+fn normalized_cycle(mut cycle: Vec<usize>) -> Vec<usize> {
+    let position = cycle.iter().position_min().unwrap();
+    cycle.rotate_left(position);
+    if cycle[cycle.len() - 1] < cycle[1] {
+        cycle[1..].reverse();
+    }
+    cycle
+}
+
+#[cfg(test)]
+mod test {
+    use super::Tiling;
+    use crate::coxeter_diagram::{CoxeterDiagram, CoxeterDiagramType, CoxeterLabel};
+    use itertools::Itertools;
+
+    #[test]
+    fn test_euclidean_tiling() {
+        // The triangular tiling, the hexagonal tiling and the square tiling.
+        [
+            (CoxeterDiagramType::AffineA(2), vec![0], 3, 6),
+            (CoxeterDiagramType::AffineA(2), vec![0, 1, 2], 6, 3),
+            (CoxeterDiagramType::AffineC(2), vec![0], 4, 4),
+        ]
+        .into_iter()
+        .for_each(|(coxeter_diagram_type, ringed, tile_size, vertex_degree)| {
+            let tiling =
+                Tiling::new(CoxeterDiagram::from(coxeter_diagram_type), &ringed, 12).unwrap();
+            let vertices = tiling.vertices();
+            let distance = |i: usize, j: usize| {
+                (vertices[i][0] - vertices[j][0]).hypot(vertices[i][1] - vertices[j][1])
+            };
+            let edges = tiling.edges();
+            assert!(edges
+                .iter()
+                .all(|&(i, j)| (distance(i, j) - distance(edges[0].0, edges[0].1)).abs() < 1e-9));
+            assert!(tiling.tiles().iter().all(|tile| tile.len() == tile_size));
+            // Consecutive vertices of a tile are joined by edges.
+            assert!(tiling.tiles().iter().all(|tile| {
+                tile.iter()
+                    .circular_tuple_windows()
+                    .all(|(&i, &j)| edges.contains(&(i.min(j), i.max(j))))
+            }));
+            assert_eq!(
+                edges.iter().filter(|&&(i, j)| i == 0 || j == 0).count(),
+                vertex_degree
+            );
+            // Each tile appears once, the same way on every construction.
+            let other =
+                Tiling::new(CoxeterDiagram::from(coxeter_diagram_type), &ringed, 12).unwrap();
+            assert_eq!(tiling.tiles(), other.tiles());
+            assert!(tiling
+                .tiles()
+                .iter()
+                .map(|tile| tile.iter().sorted().collect_vec())
+                .all_unique());
+        });
+    }
+
+    #[test]
+    fn test_hyperbolic_tiling() {
+        // The order 7 triangular tiling.
+        let tiling = Tiling::new(
+            CoxeterDiagram::new(
+                3,
+                [
+                    ((0, 1), CoxeterLabel::Finite(3)),
+                    ((1, 2), CoxeterLabel::Finite(7)),
+                ],
+            )
+            .unwrap(),
+            &[0],
+            10,
+        )
+        .unwrap();
+        let vertices = tiling.vertices();
+        let norm = |i: usize| vertices[i][0] * vertices[i][0] + vertices[i][1] * vertices[i][1];
+        assert!((0..vertices.len()).all(|i| norm(i) < 1.0));
+        let distance = |i: usize, j: usize| {
+            let difference = (vertices[i][0] - vertices[j][0]).powi(2)
+                + (vertices[i][1] - vertices[j][1]).powi(2);
+            (1.0 + 2.0 * difference / ((1.0 - norm(i)) * (1.0 - norm(j)))).acosh()
+        };
+        let edges = tiling.edges();
+        assert!(edges
+            .iter()
+            .all(|&(i, j)| (distance(i, j) - distance(edges[0].0, edges[0].1)).abs() < 1e-6));
+        assert!(tiling.tiles().iter().all(|tile| tile.len() == 3));
+        assert_eq!(
+            tiling
+                .tiles()
+                .iter()
+                .filter(|tile| tile.contains(&0))
+                .count(),
+            7
+        );
+        assert!(Tiling::new(CoxeterDiagram::from(CoxeterDiagramType::A(3)), &[0], 4).is_none());
+        assert!(Tiling::new(
+            CoxeterDiagram::from(CoxeterDiagramType::AffineA(2)),
+            &[3],
+            4
+        )
+        .is_none());
+        assert!(Tiling::new(
+            CoxeterDiagram::from(CoxeterDiagramType::AffineA(3)),
+            &[0],
+            4
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,222 @@
+use super::coxeter_diagram::{CoxeterDiagram, CoxeterGroupType};
+use super::numeric::{point_key, reflect, weight_orbit};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+// The uniform polytope of Wythoff's construction: the convex hull of the orbit of the point `p` in
+// fundamental weight coordinates with `p_i = 1` on the ringed nodes and `p_i = 0` elsewhere. Its
+// faces of dimension `k` are the images of the faces spanned by `W_J p`, where `J` runs over the
+// sets of `k` nodes each of whose connected components contains a ringed node.
+pub struct UniformPolytope {
+    coxeter_diagram: CoxeterDiagram,
+    ringed: Vec<usize>,
+    vertices: Vec<Vec<f64>>,
+    // `permutations[j][v]` is the index of `s_j(v)`.
+    permutations: Vec<Vec<usize>>,
+}
+
+impl UniformPolytope {
+    pub fn new(coxeter_diagram: CoxeterDiagram, ringed: &[usize]) -> Option<Self> {
+        let rank = coxeter_diagram.rank();
+        if ringed.is_empty()
+            || ringed.iter().any(|&i| i >= rank)
+            || coxeter_diagram.coxeter_group_type() != CoxeterGroupType::Elliptic
+        {
+            return None;
+        }
+        let schlafli_matrix = coxeter_diagram.numeric_schlafli_matrix();
+        let point = (0..rank)
+            .map(|i| ringed.contains(&i) as u8 as f64)
+            .collect_vec();
+        let points = weight_orbit(&schlafli_matrix, &[point]);
+        let indices: HashMap<_, _> = points
+            .iter()
+            .enumerate()
+            .map(|(index, point)| (point_key(point), index))
+            .collect();
+        let permutations = (0..rank)
+            .map(|j| {
+                points
+                    .iter()
+                    .map(|point| indices[&point_key(&reflect(&schlafli_matrix, point, j))])
+                    .collect_vec()
+            })
+            .collect_vec();
+        // With the Cholesky factorization `B = L L^T` of the Schlafli form, the point with
+        // fundamental weight coordinates `p` has orthonormal coordinates `L^-1 p`.
+        let mut cholesky = vec![vec![0.0; rank]; rank];
+        (0..rank).for_each(|i| {
+            (0..=i).for_each(|j| {
+                let sum = schlafli_matrix[i][j]
+                    - (0..j).map(|k| cholesky[i][k] * cholesky[j][k]).sum::<f64>();
+                cholesky[i][j] = if i == j {
+                    sum.sqrt()
+                } else {
+                    sum / cholesky[j][j]
+                };
+            });
+        });
+        let vertices = points
+            .iter()
+            .map(|point| {
+                let mut coordinates = vec![0.0; rank];
+                (0..rank).for_each(|i| {
+                    coordinates[i] = (point[i]
+                        - (0..i).map(|k| cholesky[i][k] * coordinates[k]).sum::<f64>())
+                        / cholesky[i][i];
+                });
+                coordinates
+            })
+            .collect();
+        Some(Self {
+            coxeter_diagram,
+            ringed: ringed.to_vec(),
+            vertices,
+            permutations,
+        })
+    }
+
+    // Coordinates in an orthonormal basis, the first vertex being the one in the fundamental
+    // chamber.
+    pub fn vertices(&self) -> &[Vec<f64>] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> Vec<(usize, usize)> {
+        self.faces(1)
+            .into_iter()
+            .map(|edge| (edge[0], edge[1]))
+            .collect()
+    }
+
+    // The faces of the given dimension as sorted lists of vertex indices.
+    pub fn faces(&self, dimension: usize) -> Vec<Vec<usize>> {
+        (0..self.coxeter_diagram.rank())
+            .combinations(dimension)
+            .filter(|nodes| {
+                self.coxeter_diagram
+                    .subdiagram(nodes)
+                    .connected_components()
+                    .iter()
+                    .all(|component| component.iter().any(|&i| self.ringed.contains(&nodes[i])))
+            })
+            .flat_map(|nodes| {
+                let face = self
+                    .orbit(&nodes, vec![0])
+                    .into_iter()
+                    .flatten()
+                    .sorted()
+                    .collect_vec();
+                self.orbit(&(0..self.coxeter_diagram.rank()).collect_vec(), face)
+            })
+            .collect()
+    }
+
+    // The numbers of faces of dimensions `0..rank`.
+    pub fn f_vector(&self) -> Vec<usize> {
+        (0..self.coxeter_diagram.rank())
+            .map(|dimension| self.faces(dimension).len())
+            .collect()
+    }
+
+    // The orbit of a sorted set of vertices under the generators `s_j` for the given nodes.
+    fn orbit(&self, nodes: &[usize], vertices: Vec<usize>) -> Vec<Vec<usize>> {
+        let mut keys = HashSet::from([vertices.clone()]);
+        let mut orbit = vec![vertices];
+        let mut index = 0;
+        while index < orbit.len() {
+            nodes.iter().for_each(|&j| {
+                let image = orbit[index]
+                    .iter()
+                    .map(|&v| self.permutations[j][v])
+                    .sorted()
+                    .collect_vec();
+                if keys.insert(image.clone()) {
+                    orbit.push(image);
+                }
+            });
+            index += 1;
+        }
+        orbit
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::UniformPolytope;
+    use crate::coxeter_diagram::{CoxeterDiagram, CoxeterDiagramType};
+    use itertools::Itertools;
+
+    #[test]
+    fn test_uniform_polytope() {
+        [
+            (CoxeterDiagramType::A(3), vec![0], vec![4, 6, 4]),
+            (CoxeterDiagramType::A(3), vec![0, 2], vec![12, 24, 14]),
+            (CoxeterDiagramType::B(3), vec![0, 1, 2], vec![48, 72, 26]),
+            (CoxeterDiagramType::H(3), vec![2], vec![20, 30, 12]),
+            // The 600-cell, the 120-cell and the 24-cell.
+            (CoxeterDiagramType::H(4), vec![0], vec![120, 720, 1200, 600]),
+            (CoxeterDiagramType::H(4), vec![3], vec![600, 1200, 720, 120]),
+            (CoxeterDiagramType::F(4), vec![0], vec![24, 96, 96, 24]),
+        ]
+        .into_iter()
+        .for_each(|(coxeter_diagram_type, ringed, f_vector)| {
+            let polytope =
+                UniformPolytope::new(CoxeterDiagram::from(coxeter_diagram_type), &ringed).unwrap();
+            assert_eq!(polytope.f_vector(), f_vector);
+            // Euler's relation for the boundary sphere.
+            assert_eq!(
+                f_vector
+                    .iter()
+                    .enumerate()
+                    .map(|(dimension, &count)| if dimension % 2 == 0 {
+                        count as i64
+                    } else {
+                        -(count as i64)
+                    })
+                    .sum::<i64>(),
+                if f_vector.len() % 2 == 1 { 2 } else { 0 },
+            );
+            // All edges have the same length and all vertices the same norm.
+            let vertices = polytope.vertices();
+            let distance = |i: usize, j: usize| {
+                vertices[i]
+                    .iter()
+                    .zip(&vertices[j])
+                    .map(|(x, y)| (x - y) * (x - y))
+                    .sum::<f64>()
+                    .sqrt()
+            };
+            let edges = polytope.edges();
+            assert!(edges
+                .iter()
+                .all(|&(i, j)| (distance(i, j) - distance(edges[0].0, edges[0].1)).abs() < 1e-9));
+            let norm = |i: usize| vertices[i].iter().map(|x| x * x).sum::<f64>();
+            assert!((0..vertices.len()).all(|i| (norm(i) - norm(0)).abs() < 1e-9));
+        });
+        // The 4_21 polytope, whose vertices are the roots of E8, with facets 7-simplices and
+        // 7-orthoplexes.
+        let polytope =
+            UniformPolytope::new(CoxeterDiagram::from(CoxeterDiagramType::E(8)), &[7]).unwrap();
+        assert_eq!(polytope.vertices().len(), 240);
+        assert_eq!(polytope.edges().len(), 6720);
+        assert_eq!(
+            polytope
+                .faces(7)
+                .iter()
+                .map(|facet| facet.len())
+                .counts()
+                .into_iter()
+                .sorted()
+                .collect_vec(),
+            vec![(8, 17280), (14, 2160)],
+        );
+        assert!(
+            UniformPolytope::new(CoxeterDiagram::from(CoxeterDiagramType::A(3)), &[99]).is_none()
+        );
+        assert!(
+            UniformPolytope::new(CoxeterDiagram::from(CoxeterDiagramType::AffineA(2)), &[0])
+                .is_none()
+        );
+    }
+}